@@ -0,0 +1,43 @@
+
+// Accepted answers live next to the inputs: `example_input.txt` is checked
+// against `example_expected.txt`, `input.txt` against `expected.txt`, etc.
+// Each line records one part, e.g. `part2: 2286`. A `-` answer marks a part
+// that does not apply to that input, such as day 8 part 2 on the part 1 example.
+
+use std::{fs, io, path::{Path, PathBuf}};
+
+pub const NOT_APPLICABLE: &str = "-";
+
+pub fn expected_path(input_path: &Path) -> PathBuf {
+    let file_name = input_path
+        .file_name()
+        .map(|name| name.to_string_lossy().replace("input", "expected"))
+        .unwrap_or_default();
+    input_path.with_file_name(file_name)
+}
+
+pub fn expected_answer(input_path: &Path, part: u8) -> io::Result<Option<String>> {
+    let expected = match fs::read_to_string(expected_path(input_path)) {
+        Ok(expected) => expected,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(error) => return Err(error),
+    };
+
+    let prefix = format!("part{}:", part);
+    Ok(expected
+        .lines()
+        .find_map(|line| line.strip_prefix(&prefix))
+        .map(|answer| answer.trim().to_string()))
+}
+
+pub fn input_paths(directory: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(directory)?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+            file_name.ends_with(".txt") && file_name.contains("input")
+        })
+        .collect();
+    paths.sort();
+    Ok(paths)
+}
@@ -0,0 +1,175 @@
+
+// Baseline files hold one line per solver with its median timings in
+// nanoseconds: `<day> <part> <parse_ns> <solve_ns> <total_ns>`. Lines
+// starting with `#` are comments. Lines written before the total was recorded
+// have no `<total_ns>`, and their total is not compared.
+
+use std::{collections::BTreeMap, fs, io, path::Path, time::Duration};
+
+use super::registry::Solver;
+use super::solution::{Answer, RunError};
+use super::utils::format_duration;
+
+// The median of the totals is not the sum of the parse and solve medians, so
+// it is recorded on its own
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Medians {
+    pub parse: Duration,
+    pub solve: Duration,
+    pub total: Option<Duration>,
+}
+
+pub type Baseline = BTreeMap<(u8, u8), Medians>;
+
+struct Stats {
+    min: Duration,
+    median: Duration,
+    mean: Duration,
+    stddev: Duration,
+}
+
+impl From<&[Duration]> for Stats {
+    fn from(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[sorted.len() / 2 - 1] + sorted[sorted.len() / 2]) / 2
+        } else {
+            sorted[sorted.len() / 2]
+        };
+
+        let nanos: Vec<f64> = sorted.iter().map(|sample| sample.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / nanos.len() as f64;
+        let variance = nanos.iter().map(|sample| (sample - mean).powi(2)).sum::<f64>() / nanos.len() as f64;
+
+        Stats {
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean as u64),
+            stddev: Duration::from_nanos(variance.sqrt() as u64),
+        }
+    }
+}
+
+pub struct BenchResult {
+    answer: Answer,
+    runs: u32,
+    warmup: u32,
+    parse: Stats,
+    solve: Stats,
+    total: Stats,
+}
+
+impl BenchResult {
+    pub fn medians(&self) -> Medians {
+        Medians {
+            parse: self.parse.median,
+            solve: self.solve.median,
+            total: Some(self.total.median),
+        }
+    }
+}
+
+pub fn bench(solver: &Solver, input: &str, runs: u32, warmup: u32) -> Result<BenchResult, RunError> {
+    for _ in 0..warmup {
+        (solver.run)(input)?;
+    }
+
+    let mut answer = None;
+    let mut samples = vec![];
+    for _ in 0..runs {
+        let outcome = (solver.run)(input)?;
+        samples.push(outcome.timings);
+        answer = Some(outcome.answer);
+    }
+
+    let parse: Vec<Duration> = samples.iter().map(|timings| timings.parse).collect();
+    let solve: Vec<Duration> = samples.iter().map(|timings| timings.solve).collect();
+    let total: Vec<Duration> = samples.iter().map(|timings| timings.total()).collect();
+
+    Ok(BenchResult {
+        answer: answer.unwrap(),
+        runs,
+        warmup,
+        parse: Stats::from(parse.as_slice()),
+        solve: Stats::from(solve.as_slice()),
+        total: Stats::from(total.as_slice()),
+    })
+}
+
+fn change(median: Duration, baseline: Option<Duration>) -> String {
+    match baseline {
+        Some(baseline) if !baseline.is_zero() => {
+            let change = (median.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0;
+            format!("{:+.1}%", change)
+        }
+        _ => "-".to_string(),
+    }
+}
+
+pub fn print_report(solver: &Solver, result: &BenchResult, baseline: Option<&Medians>) {
+    println!("Day {} part {}: {} ({} runs after {} warmup)", solver.day, solver.part, result.answer, result.runs, result.warmup);
+    println!("       {:>12}  {:>12}  {:>12}  {:>12}  {:>9}", "min", "median", "mean", "stddev", "baseline");
+
+    let rows = [
+        ("Parse", &result.parse, baseline.map(|medians| medians.parse)),
+        ("Solve", &result.solve, baseline.map(|medians| medians.solve)),
+        ("Total", &result.total, baseline.and_then(|medians| medians.total)),
+    ];
+    for (name, stats, baseline) in rows {
+        println!(
+            "{:<5}  {:>12}  {:>12}  {:>12}  {:>12}  {:>9}",
+            name,
+            format_duration(stats.min),
+            format_duration(stats.median),
+            format_duration(stats.mean),
+            format_duration(stats.stddev),
+            change(stats.median, baseline),
+        );
+    }
+}
+
+fn invalid_data(line: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("invalid baseline line: {}", line))
+}
+
+pub fn read_baseline(path: &Path) -> io::Result<Baseline> {
+    let mut baseline = Baseline::new();
+    for line in fs::read_to_string(path)?.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let fields: Vec<u64> = line
+            .split_ascii_whitespace()
+            .map(|field| field.parse().map_err(|_| invalid_data(line)))
+            .collect::<io::Result<_>>()?;
+        let (day, part, parse, solve, total) = match fields[..] {
+            [day, part, parse, solve] => (day, part, parse, solve, None),
+            [day, part, parse, solve, total] => (day, part, parse, solve, Some(total)),
+            _ => return Err(invalid_data(line)),
+        };
+        let day = u8::try_from(day).map_err(|_| invalid_data(line))?;
+        let part = u8::try_from(part).map_err(|_| invalid_data(line))?;
+        baseline.insert((day, part), Medians {
+            parse: Duration::from_nanos(parse),
+            solve: Duration::from_nanos(solve),
+            total: total.map(Duration::from_nanos),
+        });
+    }
+    Ok(baseline)
+}
+
+pub fn write_baseline(path: &Path, baseline: &Baseline) -> io::Result<()> {
+    let mut content = String::from("# day part parse_median_ns solve_median_ns total_median_ns\n");
+    for ((day, part), medians) in baseline {
+        content.push_str(&format!("{} {} {} {}", day, part, medians.parse.as_nanos(), medians.solve.as_nanos()));
+        if let Some(total) = medians.total {
+            content.push_str(&format!(" {}", total.as_nanos()));
+        }
+        content.push('\n');
+    }
+    fs::write(path, content)
+}
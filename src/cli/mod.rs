@@ -0,0 +1,422 @@
+
+use std::{fmt, path::PathBuf, str::FromStr};
+
+use super::generate::DEFAULT_SIZE;
+use super::report::Format;
+use super::trace::Level;
+
+pub const USAGE: &str = "\
+Usage: aoc2023 --day <N> [--part <P>] [--input <PATH>] [--threads <N>] [--format <FORMAT> | --cross-check]
+                     [-v | -vv] [--trace-file <PATH>]
+       aoc2023 <MODULE> [--input <PATH>] [--threads <N>] [--format <FORMAT> | --cross-check]
+                     [-v | -vv] [--trace-file <PATH>]
+       aoc2023 verify [--day <N> [--part <P>]] [-v | -vv] [--trace-file <PATH>]
+       aoc2023 run-all [--day <N> [--part <P>]] [--threads <N>] [--serial] [--format <FORMAT>]
+                     [-v | -vv] [--trace-file <PATH>]
+       aoc2023 bench --day <N> [--part <P>] [--input <PATH>] [--runs <N>] [--warmup <N>]
+                     [--baseline <PATH>] [--save-baseline <PATH>] [--threads <N>]
+       aoc2023 new-day <N>
+       aoc2023 generate --day <N> [--size <N>] [--seed <N>] [--output <PATH>] [--cross-check]
+       aoc2023 watch --day <N> [--part <P>] [--input <PATH>] [--interval <MS>]
+
+Commands:
+  verify              Check every input of the selected days against its *expected*.txt answers
+  run-all             Run the selected solvers concurrently on their default input and report timings, slowest first
+  bench               Time the selected solvers over many runs and compare them to a baseline
+  new-day             Create src/dayNN with a solution template and register it
+  generate            Write a random valid input for a day, as large as asked, to stress its solvers
+  watch               Re-run the selected parts whenever one of the day's inputs changes, and show what moved
+
+Options:
+  -d, --day <N>       Puzzle day to run (both parts if --part is omitted)
+  -p, --part <P>      Puzzle part to run (1 or 2)
+  <MODULE>            Legacy module number, e.g. 12 for day 6 part 2
+  -i, --input <PATH>  Puzzle input file, `-` for stdin (default: src/dayNN/input.txt, every input of the day for watch)
+      --runs <N>      Measured runs per solver for bench (default: 10)
+      --warmup <N>    Unmeasured runs before measuring for bench (default: 3)
+      --baseline <PATH>
+                      Baseline file to report regressions against
+      --save-baseline <PATH>
+                      Record the measured medians into a baseline file
+      --threads <N>   Worker threads for concurrent solvers and parallel solver loops (default: one per CPU)
+      --serial        Run the solvers of run-all one after the other, for timing comparisons
+                      (always the case when built with the count-allocations feature)
+      --format <FORMAT>
+                      Output of run and run-all: text, json or csv (default: text)
+      --size <N>      Size of the generated input, in lines, nodes or cells per side (default: 100)
+      --seed <N>      Seed of the generated input, the same seed gives the same input (default: 0)
+  -o, --output <PATH> File to write the generated input to (default: stdout)
+      --cross-check   Compare the answers with the slow reference solvers, and on a disagreement print
+                      the smallest input that still disagrees. generate then checks its input instead of
+                      printing it
+      --interval <MS> Time between two checks of the watched files (default: 500)
+  -v, --verbose       Trace what the solvers do on stderr, -vv to trace every step
+      --trace-file <PATH>
+                      File to write the trace to instead of stderr (implies -v)
+  -h, --help          Print this help";
+
+#[derive(Debug, PartialEq)]
+pub enum Selection {
+    Module(u8),
+    Day { day: u8, part: Option<u8> },
+}
+
+impl fmt::Display for Selection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Selection::Module(module) => write!(f, "module {}", module),
+            Selection::Day { day, part: None } => write!(f, "day {}", day),
+            Selection::Day { day, part: Some(part) } => write!(f, "day {} part {}", day, part),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum InputSource {
+    Default,
+    Stdin,
+    File(PathBuf),
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct TraceArgs {
+    // `None` when tracing is off
+    pub level: Option<Level>,
+    pub file: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RunArgs {
+    pub selection: Selection,
+    pub input: InputSource,
+    pub threads: Option<usize>,
+    pub format: Format,
+    pub cross_check: bool,
+    pub trace: TraceArgs,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct VerifyArgs {
+    pub selection: Option<Selection>,
+    pub trace: TraceArgs,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RunAllArgs {
+    pub selection: Option<Selection>,
+    pub threads: Option<usize>,
+    pub serial: bool,
+    pub format: Format,
+    pub trace: TraceArgs,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BenchArgs {
+    pub selection: Selection,
+    pub input: InputSource,
+    pub runs: u32,
+    pub warmup: u32,
+    pub baseline: Option<PathBuf>,
+    pub save_baseline: Option<PathBuf>,
+    pub threads: Option<usize>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct GenerateArgs {
+    pub day: u8,
+    pub size: usize,
+    pub seed: u64,
+    pub output: Option<PathBuf>,
+    pub cross_check: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct WatchArgs {
+    pub selection: Selection,
+    // Every input of the day when `None`
+    pub input: Option<PathBuf>,
+    pub interval_ms: u64,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run(RunArgs),
+    Verify(VerifyArgs),
+    RunAll(RunAllArgs),
+    Bench(BenchArgs),
+    NewDay(u8),
+    Generate(GenerateArgs),
+    Watch(WatchArgs),
+    Help,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ArgsError(pub String);
+
+impl fmt::Display for ArgsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+fn parse_number<T: FromStr>(flag: &str, value: Option<String>) -> Result<T, ArgsError> {
+    let value = value.ok_or_else(|| ArgsError(format!("Missing value for {}", flag)))?;
+    value
+        .parse()
+        .map_err(|_| ArgsError(format!("Invalid value for {}: {}", flag, value)))
+}
+
+#[derive(Default)]
+struct Options {
+    flags: Vec<&'static str>,
+    help: bool,
+    module: Option<u8>,
+    day: Option<u8>,
+    part: Option<u8>,
+    input: Option<InputSource>,
+    runs: Option<u32>,
+    warmup: Option<u32>,
+    baseline: Option<PathBuf>,
+    save_baseline: Option<PathBuf>,
+    threads: Option<usize>,
+    serial: bool,
+    format: Option<Format>,
+    size: Option<usize>,
+    seed: Option<u64>,
+    output: Option<PathBuf>,
+    cross_check: bool,
+    verbosity: u8,
+    trace_file: Option<PathBuf>,
+    interval_ms: Option<u64>,
+}
+
+fn parse_path(flag: &str, value: Option<String>) -> Result<PathBuf, ArgsError> {
+    value
+        .map(PathBuf::from)
+        .ok_or_else(|| ArgsError(format!("Missing value for {}", flag)))
+}
+
+impl Options {
+    fn parse<I: Iterator<Item = String>>(mut args: I) -> Result<Options, ArgsError> {
+        let mut options = Options::default();
+
+        while let Some(arg) = args.next() {
+            let flag = match arg.as_str() {
+                "-h" | "--help" => {
+                    options.help = true;
+                    "--help"
+                }
+                "-d" | "--day" => {
+                    options.day = Some(parse_number("--day", args.next())?);
+                    "--day"
+                }
+                "-p" | "--part" => {
+                    options.part = Some(parse_number("--part", args.next())?);
+                    "--part"
+                }
+                "-i" | "--input" => {
+                    options.input = match args.next().as_deref() {
+                        Some("-") => Some(InputSource::Stdin),
+                        Some(path) => Some(InputSource::File(PathBuf::from(path))),
+                        None => return Err(ArgsError("Missing value for --input".to_string())),
+                    };
+                    "--input"
+                }
+                "--runs" => {
+                    options.runs = Some(parse_number("--runs", args.next())?);
+                    "--runs"
+                }
+                "--warmup" => {
+                    options.warmup = Some(parse_number("--warmup", args.next())?);
+                    "--warmup"
+                }
+                "--baseline" => {
+                    options.baseline = Some(parse_path("--baseline", args.next())?);
+                    "--baseline"
+                }
+                "--save-baseline" => {
+                    options.save_baseline = Some(parse_path("--save-baseline", args.next())?);
+                    "--save-baseline"
+                }
+                "--threads" => {
+                    options.threads = Some(parse_number("--threads", args.next())?);
+                    "--threads"
+                }
+                "--serial" => {
+                    options.serial = true;
+                    "--serial"
+                }
+                "--format" => {
+                    options.format = Some(parse_number("--format", args.next())?);
+                    "--format"
+                }
+                "--size" => {
+                    options.size = Some(parse_number("--size", args.next())?);
+                    "--size"
+                }
+                "--seed" => {
+                    options.seed = Some(parse_number("--seed", args.next())?);
+                    "--seed"
+                }
+                "-o" | "--output" => {
+                    options.output = Some(parse_path("--output", args.next())?);
+                    "--output"
+                }
+                "--cross-check" => {
+                    options.cross_check = true;
+                    "--cross-check"
+                }
+                "-v" | "--verbose" => {
+                    options.verbosity += 1;
+                    "--verbose"
+                }
+                "-vv" => {
+                    options.verbosity += 2;
+                    "--verbose"
+                }
+                "--trace-file" => {
+                    options.trace_file = Some(parse_path("--trace-file", args.next())?);
+                    "--trace-file"
+                }
+                "--interval" => {
+                    options.interval_ms = Some(parse_number("--interval", args.next())?);
+                    "--interval"
+                }
+                _ if !arg.starts_with('-') && options.module.is_none() => {
+                    options.module = Some(parse_number("<MODULE>", Some(arg))?);
+                    "<MODULE>"
+                }
+                _ => return Err(ArgsError(format!("Unexpected argument: {}", arg))),
+            };
+            options.flags.push(flag);
+        }
+
+        Ok(options)
+    }
+
+    fn selection(&self) -> Result<Option<Selection>, ArgsError> {
+        match (self.module, self.day, self.part) {
+            (Some(module), None, None) => Ok(Some(Selection::Module(module))),
+            (None, Some(day), part) => Ok(Some(Selection::Day { day, part })),
+            (None, None, Some(_)) => Err(ArgsError("--part requires --day".to_string())),
+            (None, None, None) => Ok(None),
+            _ => Err(ArgsError("<MODULE> cannot be combined with --day/--part".to_string())),
+        }
+    }
+
+    fn required_selection(&self) -> Result<Selection, ArgsError> {
+        self.selection()?.ok_or_else(|| ArgsError("Missing puzzle selection".to_string()))
+    }
+
+    fn trace(&self) -> TraceArgs {
+        let level = match (self.verbosity, &self.trace_file) {
+            (0, None) => None,
+            (0 | 1, _) => Some(Level::Info),
+            _ => Some(Level::Debug),
+        };
+        TraceArgs { level, file: self.trace_file.clone() }
+    }
+
+    // Every command only accepts the selection flags plus its own
+    fn allow(&self, command: &str, allowed: &[&str]) -> Result<(), ArgsError> {
+        let selection_flags = ["--help", "--day", "--part", "<MODULE>"];
+        match self.flags.iter().find(|flag| !selection_flags.contains(flag) && !allowed.contains(flag)) {
+            Some(flag) => Err(ArgsError(format!("{} cannot be used with {}", flag, command))),
+            None => Ok(()),
+        }
+    }
+}
+
+pub fn parse_args<I: Iterator<Item = String>>(args: I) -> Result<Command, ArgsError> {
+    let mut args = args.peekable();
+    let subcommand = match args.peek().map(String::as_str) {
+        Some("verify" | "run-all" | "bench" | "new-day" | "generate" | "watch") => args.next(),
+        _ => None,
+    };
+
+    let options = Options::parse(args)?;
+    if options.help {
+        return Ok(Command::Help);
+    }
+
+    match subcommand.as_deref() {
+        Some("verify") => {
+            options.allow("verify", &["--verbose", "--trace-file"])?;
+            Ok(Command::Verify(VerifyArgs {
+                selection: options.selection()?,
+                trace: options.trace(),
+            }))
+        }
+        Some("run-all") => {
+            options.allow("run-all", &["--threads", "--serial", "--format", "--verbose", "--trace-file"])?;
+            Ok(Command::RunAll(RunAllArgs {
+                selection: options.selection()?,
+                threads: options.threads.map(|threads| threads.max(1)),
+                serial: options.serial,
+                format: options.format.unwrap_or_default(),
+                trace: options.trace(),
+            }))
+        }
+        Some("bench") => {
+            options.allow("bench", &["--input", "--runs", "--warmup", "--baseline", "--save-baseline", "--threads"])?;
+            Ok(Command::Bench(BenchArgs {
+                selection: options.required_selection()?,
+                input: options.input.unwrap_or(InputSource::Default),
+                runs: options.runs.unwrap_or(10).max(1),
+                warmup: options.warmup.unwrap_or(3),
+                baseline: options.baseline,
+                save_baseline: options.save_baseline,
+                threads: options.threads.map(|threads| threads.max(1)),
+            }))
+        }
+        Some("new-day") => {
+            options.allow("new-day", &[])?;
+            match options.required_selection()? {
+                Selection::Module(day) | Selection::Day { day, part: None } if (1..=25).contains(&day) => Ok(Command::NewDay(day)),
+                _ => Err(ArgsError("new-day expects a day between 1 and 25".to_string())),
+            }
+        }
+        Some("generate") => {
+            options.allow("generate", &["--size", "--seed", "--output", "--cross-check"])?;
+            match options.required_selection()? {
+                Selection::Day { day, part: None } => Ok(Command::Generate(GenerateArgs {
+                    day,
+                    size: options.size.unwrap_or(DEFAULT_SIZE),
+                    seed: options.seed.unwrap_or(0),
+                    output: options.output,
+                    cross_check: options.cross_check,
+                })),
+                _ => Err(ArgsError("generate expects --day without --part".to_string())),
+            }
+        }
+        Some("watch") => {
+            options.allow("watch", &["--input", "--interval"])?;
+            let selection = options.required_selection()?;
+            let input = match options.input {
+                Some(InputSource::Stdin) => return Err(ArgsError("watch needs files, not stdin".to_string())),
+                Some(InputSource::File(path)) => Some(path),
+                Some(InputSource::Default) | None => None,
+            };
+            Ok(Command::Watch(WatchArgs {
+                selection,
+                input,
+                interval_ms: options.interval_ms.unwrap_or(500).max(1),
+            }))
+        }
+        _ => {
+            options.allow("run", &["--input", "--threads", "--format", "--cross-check", "--verbose", "--trace-file"])?;
+            if options.cross_check && options.format.is_some() {
+                return Err(ArgsError("--cross-check cannot be combined with --format".to_string()));
+            }
+            let trace = options.trace();
+            Ok(Command::Run(RunArgs {
+                selection: options.required_selection()?,
+                input: options.input.unwrap_or(InputSource::Default),
+                threads: options.threads.map(|threads| threads.max(1)),
+                format: options.format.unwrap_or_default(),
+                cross_check: options.cross_check,
+                trace,
+            }))
+        }
+    }
+}
@@ -0,0 +1,113 @@
+
+// `--cross-check` runs the reference solvers next to the fast ones and, when
+// they disagree, cuts the input down to as few lines as still disagree.
+
+use std::panic;
+
+use super::registry::Solver;
+use super::solution::{Answer, RunError};
+use super::utils::panics::quietly;
+
+#[derive(Debug)]
+pub struct Disagreement {
+    pub input: String,
+    pub answer: Answer,
+    pub reference: Answer,
+}
+
+#[derive(Debug)]
+pub enum Verdict {
+    Agree(Answer),
+    NoReference(Answer),
+    Disagree { found: Disagreement, smallest: Disagreement },
+}
+
+// Inputs either solver rejects or panics on do not count as disagreeing
+fn disagreement(solver: &Solver, input: &str) -> Option<Disagreement> {
+    let answer = (solver.run)(input).ok()?.answer;
+    let reference = (solver.reference)(input).ok()??;
+
+    (answer != reference).then(|| Disagreement { input: input.to_string(), answer, reference })
+}
+
+// Removes ever smaller blocks of lines for as long as the solvers still disagree
+fn shrink(solver: &Solver, found: &Disagreement) -> Disagreement {
+    let mut lines: Vec<&str> = found.input.lines().collect();
+    let mut smallest = None;
+    let mut block = (lines.len() / 2).max(1);
+    loop {
+        let mut removed = false;
+        let mut start = 0;
+        while start < lines.len() {
+            let end = (start + block).min(lines.len());
+            let candidate = [&lines[..start], &lines[end..]].concat();
+            let input = candidate.join("\n");
+            // A solver panicking on a cut input is caught and needs no message
+            match quietly(|| panic::catch_unwind(|| disagreement(solver, &input))).ok().flatten() {
+                Some(disagreement) => {
+                    lines = candidate;
+                    smallest = Some(disagreement);
+                    removed = true;
+                }
+                None => start += block,
+            }
+        }
+
+        if !removed {
+            if block == 1 {
+                break;
+            }
+            block /= 2;
+        }
+    }
+
+    smallest.unwrap_or_else(|| Disagreement {
+        input: found.input.clone(),
+        answer: found.answer.clone(),
+        reference: found.reference.clone(),
+    })
+}
+
+pub fn cross_check(solver: &Solver, input: &str) -> Result<Verdict, RunError> {
+    let answer = (solver.run)(input)?.answer;
+    let reference = match (solver.reference)(input)? {
+        Some(reference) => reference,
+        None => return Ok(Verdict::NoReference(answer)),
+    };
+
+    if answer == reference {
+        return Ok(Verdict::Agree(answer));
+    }
+    let found = Disagreement { input: input.to_string(), answer, reference };
+    let smallest = shrink(solver, &found);
+    Ok(Verdict::Disagree { found, smallest })
+}
+
+// Returns whether no solver disagrees with its reference
+pub fn cross_check_all(solvers: &[&Solver], inputs: &[String]) -> bool {
+    let mut agreed = true;
+
+    for (solver, input) in solvers.iter().zip(inputs) {
+        match cross_check(solver, input) {
+            Ok(Verdict::Agree(answer)) => println!("Day {} part {}: {}, the reference agrees", solver.day, solver.part, answer),
+            Ok(Verdict::NoReference(answer)) => println!("Day {} part {}: {}, no reference answer", solver.day, solver.part, answer),
+            Ok(Verdict::Disagree { found, smallest }) => {
+                println!("Day {} part {}: {}, but the reference finds {}", solver.day, solver.part, found.answer, found.reference);
+                println!(
+                    "Smallest input where they disagree ({} lines, {} against {}):",
+                    smallest.input.lines().count(),
+                    smallest.answer,
+                    smallest.reference,
+                );
+                println!("{}", smallest.input);
+                agreed = false;
+            }
+            Err(error) => {
+                eprintln!("{}", error);
+                agreed = false;
+            }
+        }
+    }
+
+    agreed
+}
@@ -0,0 +1,100 @@
+
+use rayon::prelude::*;
+
+use super::solution::{Answer, ParseError, Solution};
+use super::trace::event;
+use super::utils::split_input_into_lines;
+
+pub fn get_value(s: &str) -> usize {
+    let mut first_number = None;
+    let mut last_number = None;
+
+    for c in s.chars() {
+        if c.is_ascii_digit() {
+            if first_number.is_none() {
+                first_number = c.to_digit(10);
+            }
+            last_number = c.to_digit(10);
+        }
+    }
+
+    let result_number = format!(
+        "{}{}",
+        first_number.unwrap_or_default(),
+        last_number.unwrap_or_default()
+    );
+    result_number.parse().unwrap()
+}
+
+fn match_and_replace(pattern: &str, replace: u8, input: &str, output: &mut [Option<u8>]) {
+    for (i, _) in input.match_indices(pattern) {
+        output[i] = Some(replace);
+    }
+}
+
+pub fn get_value_with_spelled_digits(s: &str) -> usize {
+    let mut digits = vec![None; s.len()];
+
+    match_and_replace("zero", 0, s, &mut digits);
+    match_and_replace("one", 1, s, &mut digits);
+    match_and_replace("two", 2, s, &mut digits);
+    match_and_replace("three", 3, s, &mut digits);
+    match_and_replace("four", 4, s, &mut digits);
+    match_and_replace("five", 5, s, &mut digits);
+    match_and_replace("six", 6, s, &mut digits);
+    match_and_replace("seven", 7, s, &mut digits);
+    match_and_replace("eight", 8, s, &mut digits);
+    match_and_replace("nine", 9, s, &mut digits);
+    match_and_replace("0", 0, s, &mut digits);
+    match_and_replace("1", 1, s, &mut digits);
+    match_and_replace("2", 2, s, &mut digits);
+    match_and_replace("3", 3, s, &mut digits);
+    match_and_replace("4", 4, s, &mut digits);
+    match_and_replace("5", 5, s, &mut digits);
+    match_and_replace("6", 6, s, &mut digits);
+    match_and_replace("7", 7, s, &mut digits);
+    match_and_replace("8", 8, s, &mut digits);
+    match_and_replace("9", 9, s, &mut digits);
+
+    // (position, digit)
+    let matches: Vec<(usize, u8)> = digits
+        .into_iter()
+        .enumerate()
+        .filter_map(|(position, digit)| digit.map(|digit| (position, digit)))
+        .collect();
+    let value = match (matches.first(), matches.last()) {
+        (Some((_, first)), Some((_, last))) => (first * 10 + last) as usize,
+        _ => 0,
+    };
+
+    event!(Debug, "digit_matches", line = s, matches = matches, value = value);
+    value
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(split_input_into_lines(input))
+    }
+
+    fn part1(lines: &Self::Input) -> Answer {
+        lines
+            .par_iter()
+            .map(|s| get_value(s))
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(lines: &Self::Input) -> Answer {
+        lines
+            .par_iter()
+            .map(|s| get_value_with_spelled_digits(s))
+            .sum::<usize>()
+            .into()
+    }
+}
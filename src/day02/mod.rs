@@ -0,0 +1,173 @@
+
+use std::{collections::HashMap, cmp::max, fmt};
+
+use super::solution::{Answer, ParseError, Solution};
+use super::utils::parsing::{cached_regex, parse_number};
+use super::utils::split_input_into_lines;
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+impl Color {
+    // `s` must be a slice of `line`, so that errors point at it
+    fn parse(line: &str, s: &str) -> Result<Self, ParseError> {
+        match s {
+            "red" => Ok(Color::Red),
+            "green" => Ok(Color::Green),
+            "blue" => Ok(Color::Blue),
+            _ => Err(ParseError::part(line, s, "a color: `red`, `green` or `blue`")),
+        }
+    }
+
+    pub const ALL: [Color; 3] = [Color::Red, Color::Green, Color::Blue];
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Color::Red => write!(f, "red"),
+            Color::Green => write!(f, "green"),
+            Color::Blue => write!(f, "blue"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Game {
+    pub id: u16,
+    pub sets: Vec<HashMap<Color, u16>>,
+}
+
+impl TryFrom<&str> for Game {
+    type Error = ParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let regex_game = cached_regex!(r"^Game (?P<id>\d+): ([0-9a-z ,]+)(?:; (?:[0-9a-z ,]+))*$");
+    
+        let regex_next_sets = cached_regex!(r"; ([0-9a-z ,]+)");
+
+        fn str_to_set(line: &str, s: &str) -> Result<HashMap<Color, u16>, ParseError> {
+            let regex_color = cached_regex!(r"(\d+) ([a-z]+)");
+
+            let mut result = HashMap::new();
+            for (_, [color_number, color]) in regex_color.captures_iter(s).map(|c| c.extract()) {
+                result.insert(Color::parse(line, color)?, parse_number(line, color_number)?);
+            }
+            Ok(result)
+        }
+
+        let cap = regex_game
+            .captures(s)
+            .ok_or_else(|| ParseError::line(s, "`Game <id>: <count> <color>, ...; ...`"))?;
+        let id: u16 = parse_number(s, cap.get(1).unwrap().as_str())?;
+
+        let mut sets = vec![
+            str_to_set(s, cap.get(2).unwrap().as_str())?
+        ];
+
+        for next_set in regex_next_sets.captures_iter(s) {
+            sets.push(str_to_set(s, next_set.get(0).unwrap().as_str())?);
+        }
+
+        Ok(Game {
+            id,
+            sets,
+        })
+    }
+}
+
+// The colors of a set are written red, green then blue, whatever their order in the input
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sets: Vec<String> = self.sets
+            .iter()
+            .map(|set| {
+                Color::ALL
+                    .iter()
+                    .filter_map(|color| set.get(color).map(|count| format!("{} {}", count, color)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect();
+        write!(f, "Game {}: {}", self.id, sets.join("; "))
+    }
+}
+
+impl Game {
+    pub fn compatible_with(&self, max_cubes: &HashMap<Color, u16>) -> bool {
+        for (max_key, max_value) in max_cubes {
+            for set in self.sets.iter() {
+                if let Some(value) = set.get(max_key) {
+                    if value > max_value {
+                        return false;
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    pub fn power(&self) -> usize {
+        let mut min_cubes = HashMap::from([
+            (Color::Red, 0_usize),
+            (Color::Green, 0_usize),
+            (Color::Blue, 0_usize),
+        ]);
+
+        for set in self.sets.iter() {
+            for (key, value) in set {
+                min_cubes.insert(key.clone(), max(*min_cubes.get(key).unwrap(), *value as usize));
+            }
+        }
+
+        min_cubes.get(&Color::Red).unwrap() * min_cubes.get(&Color::Green).unwrap() * min_cubes.get(&Color::Blue).unwrap()
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        split_input_into_lines(input)
+            .iter()
+            .enumerate()
+            .map(|(index, line)| Game::try_from(line.as_str()).map_err(|error| error.on_line(index)))
+            .collect()
+    }
+
+    fn part1(games: &Self::Input) -> Answer {
+        let max_cubes = HashMap::from([
+            (Color::Red, 12),
+            (Color::Green, 13),
+            (Color::Blue, 14),
+        ]);
+
+        games
+            .iter()
+            .fold(0, |acc, game| {
+                if game.compatible_with(&max_cubes) {
+                    acc + (game.id as usize)
+                } else {
+                    acc
+                }
+            })
+            .into()
+    }
+
+    fn part2(games: &Self::Input) -> Answer {
+        games
+            .iter()
+            .fold(0, |acc, game| {
+                acc + game.power()
+            })
+            .into()
+    }
+}
@@ -0,0 +1,130 @@
+
+use std::{cell::RefCell, rc::Rc};
+
+use super::solution::{Answer, ParseError, Solution};
+use super::utils::grid::{Grid, Position};
+
+type Number = Rc<RefCell<usize>>;
+#[derive(Clone, Debug)]
+pub enum PointType {
+    None,
+    Symbol,
+    Gear,
+    Number(Number),
+}
+pub type Schematic = Grid<PointType>;
+
+// Digits next to each other on a line share the same number
+fn char_to_point(char: char, previous_char: &mut Option<PointType>) -> PointType {
+    match char {
+        '0' | '1' | '2' | '3' | '4' | '5' | '6' | '7' | '8' | '9' => {
+            let value = char.to_digit(10).unwrap();
+
+            let new_point = if let Some(PointType::Number(n)) = previous_char.as_mut() {
+                let new_value = 10 * *n.borrow() + (value as usize);
+                n.replace(new_value);
+
+                PointType::Number(n.clone())
+            } else {
+                PointType::Number(Rc::new(RefCell::new(value as usize)))
+            };
+
+            *previous_char = Some(new_point.clone());
+            new_point
+        },
+        '*' => {
+            *previous_char = None;
+            PointType::Gear
+        },
+        '.' => {
+            *previous_char = None;
+            PointType::None
+        },
+        _ => {
+            *previous_char = None;
+            PointType::Symbol
+        },
+    }
+}
+
+fn neighbours(schematic: &Schematic, position: Position) -> impl Iterator<Item = &PointType> {
+    schematic.neighbours8(position).map(|neighbour| &schematic[neighbour])
+}
+
+fn there_is_a_symbol_around(schematic: &Schematic, position: Position) -> bool {
+    neighbours(schematic, position).any(|point| matches!(point, PointType::Symbol | PointType::Gear))
+}
+
+fn add_to_selected_values(rc_set: &mut Vec<Number>, new_value: &Number) {
+    let already_in_set = rc_set.iter().any(|n| Rc::ptr_eq(n, new_value));
+    if !already_in_set {
+        rc_set.push(new_value.clone());
+    }
+}
+
+pub fn sum_part_numbers(schematic: &Schematic) -> usize {
+    let mut rc_set: Vec<Number> = vec![];
+
+    for (position, point) in schematic.iter() {
+        if let PointType::Number(value) = point {
+            if there_is_a_symbol_around(schematic, position) {
+                add_to_selected_values(&mut rc_set, value);
+            }
+        }
+    }
+
+    rc_set.into_iter().fold(0, |acc, value| acc + *value.borrow())
+}
+
+fn gear_ratio(schematic: &Schematic, position: Position) -> usize {
+    let mut rc_set: Vec<Number> = vec![];
+    neighbours(schematic, position).for_each(|point| {
+        if let PointType::Number(value) = point {
+            add_to_selected_values(&mut rc_set, value);
+        }
+    });
+
+    if rc_set.len() == 2 {
+        *rc_set[0].borrow() * *rc_set[1].borrow()
+    } else {
+        0
+    }
+}
+
+pub fn sum_gear_ratios(schematic: &Schematic) -> usize {
+    let mut acc = 0;
+
+    for (position, point) in schematic.iter() {
+        if matches!(point, PointType::Gear) {
+            acc += gear_ratio(schematic, position);
+        }
+    }
+
+    acc
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Schematic;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut previous_char = None;
+        Grid::parse(input, "a character", |(x, _), char| {
+            if x == 0 {
+                previous_char = None;
+            }
+            Some(char_to_point(char, &mut previous_char))
+        })
+    }
+
+    fn part1(schematic: &Self::Input) -> Answer {
+        sum_part_numbers(schematic).into()
+    }
+
+    fn part2(schematic: &Self::Input) -> Answer {
+        sum_gear_ratios(schematic).into()
+    }
+}
@@ -0,0 +1,89 @@
+
+use std::{collections::HashSet, fmt};
+
+use super::solution::{Answer, ParseError, Solution};
+use super::utils::parsing::{parse_number, parse_numbers, split_once, strip_literal};
+use super::utils::split_input_into_lines;
+
+#[derive(Debug, PartialEq)]
+pub struct Card {
+    pub id: u32,
+    pub winning_number: HashSet<u32>,
+    pub numbers: Vec<u32>,
+}
+
+impl Card {
+    pub fn matching_numbers(&self) -> usize {
+        self.numbers.iter().filter(|number| self.winning_number.contains(number)).count()
+    }
+
+    pub fn get_points(&self) -> usize {
+        let number_of_winning_numbers = self.matching_numbers();
+
+        if number_of_winning_numbers == 0 {
+            0
+        } else {
+            2_usize.pow((number_of_winning_numbers as u32) - 1)
+        }
+    }
+}
+
+// Ids are aligned on three columns and numbers on two like in the puzzle, the
+// winning numbers sorted
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut winning_numbers: Vec<&u32> = self.winning_number.iter().collect();
+        winning_numbers.sort();
+        let column = |numbers: Vec<&u32>| numbers.iter().map(|number| format!("{:>2}", number)).collect::<Vec<_>>().join(" ");
+
+        write!(f, "Card {:>3}: {} | {}", self.id, column(winning_numbers), column(self.numbers.iter().collect()))
+    }
+}
+
+pub fn line_to_card(line: &str) -> Result<Card, ParseError> {
+    let rest = strip_literal(line, line, "Card ")?;
+    let (id, numbers) = split_once(line, rest, ":")?;
+    let (winning_numbers, numbers) = split_once(line, numbers, "|")?;
+
+    Ok(Card {
+        id: parse_number(line, id.trim())?,
+        winning_number: parse_numbers(line, winning_numbers)?.into_iter().collect(),
+        numbers: parse_numbers(line, numbers)?,
+    })
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        split_input_into_lines(input)
+            .iter()
+            .enumerate()
+            .map(|(index, line)| line_to_card(line).map_err(|error| error.on_line(index)))
+            .collect()
+    }
+
+    fn part1(cards: &Self::Input) -> Answer {
+        cards.iter().fold(0, |acc, card| acc + card.get_points()).into()
+    }
+
+    fn part2(cards: &Self::Input) -> Answer {
+        let mut number_of_cards: Vec<usize> = vec![1; cards.len()];
+        for (index, card) in cards.iter().enumerate() {
+            let number_of_current_card = number_of_cards[index];
+
+            for i in 1..=card.matching_numbers() {
+                let new_index = index + i;
+                if new_index < number_of_cards.len() {
+                    number_of_cards[new_index] += number_of_current_card;
+                }
+            }
+        }
+
+        number_of_cards.into_iter().sum::<usize>().into()
+    }
+}
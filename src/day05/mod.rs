@@ -0,0 +1,229 @@
+
+use std::fmt;
+
+use rayon::prelude::*;
+
+use super::solution::{Answer, ParseError, Solution, SolveError};
+use super::trace::event;
+use super::utils::parsing::{cached_regex, parse_number, parse_numbers, strip_literal};
+use super::utils::{split_input_into_sections, Section};
+
+#[derive(Debug, PartialEq)]
+pub struct Almanac {
+    pub seeds: Vec<usize>,
+    pub maps: Vec<Map>,
+}
+
+#[derive(Debug)]
+pub struct SeedsGroup {
+    pub start_range: usize,
+    pub range_length: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Map {
+    pub source_category: String,
+    pub destination_category: String,
+    pub mappings: Vec<Mapping>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Mapping {
+    pub source_range_start: usize,
+    pub destination_range_start: usize,
+    pub range_length: usize,
+}
+
+impl TryFrom<Vec<Section>> for Almanac {
+    type Error = ParseError;
+
+    fn try_from(sections: Vec<Section>) -> Result<Self, Self::Error> {
+        if sections.len() < 2 || sections[0].lines.len() != 1 {
+            return Err(ParseError::input("a `seeds:` line, then maps separated by blank lines"));
+        }
+
+        // Seeds
+        let seeds_line = &sections[0].lines[0];
+        let seeds: Vec<usize> = parse_numbers(seeds_line, strip_literal(seeds_line, seeds_line, "seeds:")?)?;
+        if seeds.is_empty() {
+            return Err(ParseError::line(seeds_line, "at least one seed"));
+        }
+        // Part 2 reads them as (start, length) pairs
+        if !seeds.len().is_multiple_of(2) {
+            return Err(ParseError::span(seeds_line, seeds_line.len(), 0, "a length after the last range start"));
+        }
+
+        // Maps
+        let maps = sections[1..sections.len()]
+            .iter()
+            .map(|section| Map::try_from(section.lines.as_slice()).map_err(|error| error.on_line(section.first_line_index)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Almanac {
+            seeds,
+            maps,
+        })
+    }
+}
+
+impl TryFrom<&[String]> for Map {
+    type Error = ParseError;
+
+    fn try_from(file_lines: &[String]) -> Result<Self, Self::Error> {
+        // Source & Destinations
+        let regex_names = cached_regex!(r"^([a-z]+)-to-([a-z]+) map:$");
+
+        let names_line = file_lines.first().map(String::as_str).unwrap_or_default();
+        let cap: regex::Captures<'_> = regex_names
+            .captures(names_line)
+            .ok_or_else(|| ParseError::line(names_line, "`<source>-to-<destination> map:`"))?;
+
+        let source_category = cap.get(1).unwrap().as_str().to_string();
+        let destination_category = cap.get(2).unwrap().as_str().to_string();
+
+        // Mappings
+        let mut mappings: Vec<Mapping> = file_lines[1..file_lines.len()]
+            .iter()
+            .enumerate()
+            .map(|(index, line)| Mapping::try_from(line.as_str()).map_err(|error| error.on_line(index + 1)))
+            .collect::<Result<_, _>>()?;
+        mappings.sort_by_key(|a| a.source_range_start);
+
+        Ok(Map {
+            source_category,
+            destination_category,
+            mappings,
+        })
+    }
+}
+
+impl TryFrom<&str> for Mapping {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        // Source & Destinations
+        let regex_mapping = cached_regex!(r"^([0-9]+) ([0-9]+) ([0-9]+)$");
+        let cap = regex_mapping
+            .captures(line)
+            .ok_or_else(|| ParseError::line(line, "`<destination start> <source start> <length>`"))?;
+
+        let destination_range_start = parse_number(line, cap.get(1).unwrap().as_str())?;
+        let source_range_start = parse_number(line, cap.get(2).unwrap().as_str())?;
+        let range_length = parse_number(line, cap.get(3).unwrap().as_str())?;
+
+        Ok(Mapping {
+            source_range_start,
+            destination_range_start,
+            range_length,
+        })
+    }
+}
+
+impl fmt::Display for Almanac {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "seeds: {}", self.seeds.iter().map(usize::to_string).collect::<Vec<_>>().join(" "))?;
+        for map in self.maps.iter() {
+            write!(f, "\n\n{}", map)?;
+        }
+        Ok(())
+    }
+}
+
+// The mappings come out sorted by source, as they are kept after parsing
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-to-{} map:", self.source_category, self.destination_category)?;
+        for mapping in self.mappings.iter() {
+            write!(f, "\n{}", mapping)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Mapping {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.destination_range_start, self.source_range_start, self.range_length)
+    }
+}
+
+impl Almanac {
+    pub fn map(&self, mut input: usize) -> usize {
+        for map in self.maps.iter() {
+            input = map.map(input)
+        }
+        input
+    }
+
+    // The seed followed by its number in every category, for tracing
+    pub fn chain(&self, seed: usize) -> Vec<usize> {
+        let mut chain = vec![seed];
+        for map in self.maps.iter() {
+            chain.push(map.map(*chain.last().unwrap()));
+        }
+        chain
+    }
+
+    // Part 2 reads the seeds line as (start, length) pairs
+    pub fn seeds_groups(&self) -> Vec<SeedsGroup> {
+        self.seeds
+            .chunks_exact(2)
+            .map(|pair| {
+                SeedsGroup {
+                    start_range: pair[0],
+                    range_length: pair[1],
+                }
+            })
+            .collect()
+    }
+}
+
+impl Map {
+    pub fn map(&self, input: usize) -> usize {
+        for mapping in self.mappings.iter() {
+            if input < mapping.source_range_start {
+                return input;
+            } else if input < mapping.source_range_start + mapping.range_length {
+                return mapping.destination_range_start + input - mapping.source_range_start;
+            }
+        }
+
+        input
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Almanac::try_from(split_input_into_sections(input))
+    }
+
+    fn part1(almanac: &Self::Input) -> Answer {
+        almanac
+            .seeds
+            .iter()
+            .map(|seed| {
+                event!(Debug, "seed", chain = almanac.chain(*seed));
+                almanac.map(*seed)
+            })
+            .min()
+            .unwrap() // There is at least one seed once parsed
+            .into()
+    }
+
+    fn try_part2(almanac: &Self::Input) -> Result<Answer, SolveError> {
+        // Every seed of every range is mapped independently
+        almanac
+            .seeds_groups()
+            .par_iter()
+            .flat_map(|seed| seed.start_range..seed.start_range + seed.range_length)
+            .map(|seed| almanac.map(seed))
+            .min()
+            .map(Answer::from)
+            .ok_or_else(|| SolveError::new("no seed in the seed ranges"))
+    }
+}
@@ -0,0 +1,140 @@
+
+use std::{fmt, iter::zip};
+
+use super::solution::{Answer, ParseError, Solution, SolveError};
+use super::utils::parsing::{parse_numbers, strip_literal};
+use super::utils::split_input_into_lines;
+
+#[derive(Debug, PartialEq)]
+pub struct Races {
+    pub races: Vec<Race>,
+}
+
+impl TryFrom<Vec<String>> for Races {
+    type Error = ParseError;
+
+    fn try_from(file_lines: Vec<String>) -> Result<Self, Self::Error> {
+        if file_lines.len() != 2 {
+            return Err(ParseError::input("a `Time:` line followed by a `Distance:` line"));
+        }
+
+        let times_line = &file_lines[0];
+        let times_str = strip_literal(times_line, times_line, "Time:")?;
+        let times: Vec<usize> = parse_numbers(times_line, times_str)?;
+        if times.is_empty() {
+            return Err(ParseError::line(times_line, "at least one race"));
+        }
+
+        let distance_line = &file_lines[1];
+        let distances: Vec<usize> = strip_literal(distance_line, distance_line, "Distance:")
+            .and_then(|distance_str| parse_numbers(distance_line, distance_str))
+            .map_err(|error| error.on_line(1))?;
+        if times.len() != distances.len() {
+            return Err(ParseError::line(distance_line, &format!("{} distances, one per race", times.len())).on_line(1));
+        }
+
+        let races = zip(times, distances)
+            .map(|(time_ms, record_distance_mm)| Race { time_ms, record_distance_mm })
+            .collect();
+
+        Ok(Races {
+            races
+        })
+    }
+}
+
+// Every race is a column, right-aligned two spaces after the previous one
+impl fmt::Display for Races {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (mut times, mut distances) = (String::from("Time:    "), String::from("Distance:"));
+        for race in self.races.iter() {
+            let width = race.time_ms.to_string().len().max(race.record_distance_mm.to_string().len());
+            times.push_str(&format!("  {:>width$}", race.time_ms));
+            distances.push_str(&format!("  {:>width$}", race.record_distance_mm));
+        }
+        write!(f, "{}\n{}", times, distances)
+    }
+}
+
+impl Races {
+    // Part 2 ignores the spaces between the numbers: there is only one long
+    // race. `None` when its numbers do not fit in a `usize`.
+    pub fn single_race(&self) -> Option<Race> {
+        let time_ms = self.races.iter().map(|race| race.time_ms.to_string()).collect::<String>();
+        let record_distance_mm = self.races.iter().map(|race| race.record_distance_mm.to_string()).collect::<String>();
+
+        Some(Race {
+            time_ms: time_ms.parse().ok()?,
+            record_distance_mm: record_distance_mm.parse().ok()?,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Race {
+    pub time_ms: usize,
+    pub record_distance_mm: usize,
+}
+
+impl Race {
+    pub fn number_of_ways_to_beat_the_record(&self) -> usize {
+        let half_time = if self.time_ms.is_multiple_of(2) {
+            self.time_ms / 2
+        } else {
+            self.time_ms / 2 + 1
+        };
+
+        let mut first_record = None;
+        for hold_time in 1..=half_time {
+            let distance = hold_time * (self.time_ms - hold_time);
+            if distance > self.record_distance_mm {
+                first_record = Some(hold_time);
+                break;
+            }
+        }
+
+        if let Some(first_record) = first_record {
+            self.time_ms + 1 - 2 * first_record
+        } else {
+            0
+        }
+    }
+
+    // Tries every hold time instead of relying on the symmetry
+    pub fn number_of_ways_by_trying_every_hold_time(&self) -> usize {
+        (0..=self.time_ms)
+            .filter(|hold_time| hold_time * (self.time_ms - hold_time) > self.record_distance_mm)
+            .count()
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Races;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Races::try_from(split_input_into_lines(input))
+    }
+
+    fn part1(races: &Self::Input) -> Answer {
+        races.races.iter().fold(1, |acc, race| acc * race.number_of_ways_to_beat_the_record()).into()
+    }
+
+    fn try_part2(races: &Self::Input) -> Result<Answer, SolveError> {
+        races
+            .single_race()
+            .map(|race| race.number_of_ways_to_beat_the_record().into())
+            .ok_or_else(|| SolveError::new("the single race does not fit in a usize"))
+    }
+
+    fn reference_part1(races: &Self::Input) -> Option<Answer> {
+        Some(races.races.iter().map(Race::number_of_ways_by_trying_every_hold_time).product::<usize>().into())
+    }
+
+    fn reference_part2(races: &Self::Input) -> Option<Answer> {
+        races.single_race().map(|race| race.number_of_ways_by_trying_every_hold_time().into())
+    }
+}
@@ -0,0 +1,239 @@
+
+use std::{cmp::Ordering, collections::HashMap, fmt};
+
+use super::solution::{Answer, ParseError, Solution};
+use super::trace::event;
+use super::utils::parsing::{cached_regex, parse_number};
+use super::utils::split_input_into_lines;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct InputHand {
+    pub cards: Vec<Card>,
+    pub bid: usize,
+}
+
+// In part 2, J cards become jokers: the weakest card, but wildcards for the hand type
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd)]
+pub enum Card {
+    Joker = 0,
+    Number2,
+    Number3,
+    Number4,
+    Number5,
+    Number6,
+    Number7,
+    Number8,
+    Number9,
+    T,
+    J,
+    Q,
+    K,
+    A,
+}
+
+#[derive(Debug, PartialEq, PartialOrd)]
+pub enum HandType {
+    HighCard = 0,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+#[derive(Debug)]
+pub struct TypedHand {
+    pub hand: InputHand,
+    pub hand_type: HandType,
+}
+
+impl From<char> for Card {
+    fn from(char: char) -> Self {
+        match char {
+            '2' => Card::Number2,
+            '3' => Card::Number3,
+            '4' => Card::Number4,
+            '5' => Card::Number5,
+            '6' => Card::Number6,
+            '7' => Card::Number7,
+            '8' => Card::Number8,
+            '9' => Card::Number9,
+            'T' => Card::T,
+            'J' => Card::J,
+            'Q' => Card::Q,
+            'K' => Card::K,
+            'A' => Card::A,
+            _ => panic!("Unknown card"),
+        }
+    }
+}
+
+// Jokers are written `J`, like the cards they were read from
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let char = match self {
+            Card::Joker | Card::J => 'J',
+            Card::Number2 => '2',
+            Card::Number3 => '3',
+            Card::Number4 => '4',
+            Card::Number5 => '5',
+            Card::Number6 => '6',
+            Card::Number7 => '7',
+            Card::Number8 => '8',
+            Card::Number9 => '9',
+            Card::T => 'T',
+            Card::Q => 'Q',
+            Card::K => 'K',
+            Card::A => 'A',
+        };
+        write!(f, "{}", char)
+    }
+}
+
+impl fmt::Display for InputHand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for card in self.cards.iter() {
+            write!(f, "{}", card)?;
+        }
+        write!(f, " {}", self.bid)
+    }
+}
+
+impl TryFrom<&str> for InputHand {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let regex_hand = cached_regex!(r"^([2-9TJQKA]{5}) ([0-9]+)$");
+        let cap = regex_hand
+            .captures(line)
+            .ok_or_else(|| ParseError::line(line, "five cards among `23456789TJQKA` and a bid"))?;
+        let hand_str = cap.get(1).unwrap().as_str();
+        let bid_str = cap.get(2).unwrap().as_str();
+
+        Ok(InputHand {
+            cards: hand_str.chars().map(Card::from).collect(),
+            bid: parse_number(line, bid_str)?,
+        })
+    }
+}
+
+impl InputHand {
+    pub fn with_jokers(&self) -> InputHand {
+        InputHand {
+            cards: self.cards.iter().map(|card| if *card == Card::J { Card::Joker } else { card.clone() }).collect(),
+            bid: self.bid,
+        }
+    }
+}
+
+impl From<InputHand> for TypedHand {
+    fn from(hand: InputHand) -> Self {
+        let mut cards_map: HashMap<Card, u8> = HashMap::new();
+        let mut number_of_j = 0;
+        for card in hand.cards.iter() {
+            if *card == Card::Joker {
+                number_of_j += 1;
+            } else if let Some(count) = cards_map.get_mut(card) {
+                *count += 1;
+            } else {
+                cards_map.insert(card.clone(), 1);
+            }
+        }
+
+        let mut repartition = cards_map.into_values().collect::<Vec<u8>>();
+        repartition.sort();
+        let hand_type = match repartition.len() {
+            5 => HandType::HighCard,
+            4 => HandType::OnePair,
+            3 => {
+                match number_of_j {
+                    2 | 1 => HandType::ThreeOfAKind,
+                    _ => {
+                        if *repartition.last().unwrap() == 3 {
+                            HandType::ThreeOfAKind
+                        } else {
+                            HandType::TwoPair
+                        }
+                    },
+                }
+            },
+            2 => {
+                match number_of_j {
+                    3 | 2 => HandType::FourOfAKind,
+                    1 => {
+                        if *repartition.last().unwrap() == 3 {
+                            HandType::FourOfAKind
+                        } else {
+                            HandType::FullHouse
+                        }
+                    },
+                    _ => {
+                        if *repartition.last().unwrap() == 4 {
+                            HandType::FourOfAKind
+                        } else {
+                            HandType::FullHouse
+                        }
+                    }
+                }
+            },
+            1 | 0 => HandType::FiveOfAKind,
+            _value => panic!("Impossible: {}", _value),
+        };
+
+        TypedHand {
+            hand,
+            hand_type,
+        }
+    }
+}
+
+// Ranks by type first, then by the cards in order
+pub fn compare_hands(a: &TypedHand, b: &TypedHand) -> Ordering {
+    if a.hand_type == b.hand_type {
+        a.hand.cards.partial_cmp(&b.hand.cards).unwrap()
+    } else {
+        a.hand_type.partial_cmp(&b.hand_type).unwrap()
+    }
+}
+
+pub fn total_winnings(hands: Vec<InputHand>) -> usize {
+    let mut hands = hands
+        .into_iter()
+        .map(TypedHand::from)
+        .collect::<Vec<TypedHand>>();
+
+    hands.sort_by(compare_hands);
+
+    hands
+        .into_iter()
+        .enumerate()
+        .fold(0, |acc, (rank, hand)| {
+            event!(Debug, "hand", cards = hand.hand.cards, hand_type = hand.hand_type, rank = rank + 1, bid = hand.hand.bid);
+            acc + (rank + 1) * hand.hand.bid
+        })
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<InputHand>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        split_input_into_lines(input)
+            .iter()
+            .enumerate()
+            .map(|(index, line)| InputHand::try_from(line.as_str()).map_err(|error| error.on_line(index)))
+            .collect()
+    }
+
+    fn part1(hands: &Self::Input) -> Answer {
+        total_winnings(hands.clone()).into()
+    }
+
+    fn part2(hands: &Self::Input) -> Answer {
+        total_winnings(hands.iter().map(InputHand::with_jokers).collect()).into()
+    }
+}
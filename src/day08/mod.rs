@@ -0,0 +1,223 @@
+
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+use rayon::prelude::*;
+
+use super::solution::{Answer, ParseError, Solution, SolveError};
+use super::trace::event;
+use super::utils::parsing::cached_regex;
+use super::utils::math::lcm_of;
+use super::utils::{split_input_into_sections, Section};
+
+#[derive(Debug, Eq, Hash, PartialEq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl From<char> for Direction {
+    fn from(char: char) -> Direction {
+        match char {
+            'L' => Direction::Left,
+            'R' => Direction::Right,
+            _ => panic!("Unknown direction"),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Node {
+    id: String,
+    mappings: HashMap<Direction, String>,
+}
+
+impl Hash for Node {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl fmt::Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} = ({}, {})", self.id, self.mappings[&Direction::Left], self.mappings[&Direction::Right])
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Map {
+    instructions: Vec<Direction>,
+    nodes: HashMap<String, Node>,
+}
+
+impl TryFrom<Vec<Section>> for Map {
+    type Error = ParseError;
+
+    fn try_from(sections: Vec<Section>) -> Result<Self, Self::Error> {
+        if sections.len() != 2 || sections[0].lines.len() != 1 {
+            return Err(ParseError::input("a line of instructions, a blank line, then the nodes"));
+        }
+
+        let regex_directions = cached_regex!(r"^([RL]+)$");
+        let directions_line = &sections[0].lines[0];
+        let directions_str = regex_directions
+            .captures(directions_line)
+            .ok_or_else(|| ParseError::line(directions_line, "a sequence of `L` and `R` instructions"))?
+            .get(1)
+            .unwrap()
+            .as_str();
+
+        let regex_node = cached_regex!(r"^([0-9A-Z]{3}) = \(([0-9A-Z]{3}), ([0-9A-Z]{3})\)$");
+        let node_lines = &sections[1];
+        let mut nodes = HashMap::new();
+        for (index, line) in node_lines.lines.iter().enumerate() {
+            let cap = regex_node
+                .captures(line)
+                .ok_or_else(|| ParseError::line(line, "`<node> = (<left>, <right>)`").on_line(node_lines.first_line_index + index))?;
+            let node_id = cap.get(1).unwrap().as_str();
+            let left_node_id = cap.get(2).unwrap().as_str();
+            let right_node_id = cap.get(3).unwrap().as_str();
+            nodes.insert(node_id.to_string(), Node {
+                id: node_id.to_string(),
+                mappings: HashMap::from([
+                    (Direction::Left, left_node_id.to_string()),
+                    (Direction::Right, right_node_id.to_string()),
+                ]),
+            });
+        }
+
+        // Every node has to lead somewhere, or walking the map would get stuck
+        for (index, line) in node_lines.lines.iter().enumerate() {
+            let cap = regex_node.captures(line).unwrap();
+            for target in [cap.get(2).unwrap(), cap.get(3).unwrap()] {
+                if !nodes.contains_key(target.as_str()) {
+                    return Err(ParseError::span(line, target.start(), target.len(), "a node defined in the map").on_line(node_lines.first_line_index + index));
+                }
+            }
+        }
+
+        Ok(Map {
+            instructions: directions_str.chars().map(Direction::from).collect(),
+            nodes,
+        })
+    }
+}
+
+// The nodes come out sorted by id, their order in the input is not kept
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for direction in self.instructions.iter() {
+            write!(f, "{}", if *direction == Direction::Left { 'L' } else { 'R' })?;
+        }
+        writeln!(f)?;
+
+        let mut nodes: Vec<&Node> = self.nodes.values().collect();
+        nodes.sort_by_key(|node| &node.id);
+        for node in nodes {
+            write!(f, "\n{}", node)?;
+        }
+        Ok(())
+    }
+}
+
+impl Map {
+    // The first node `is_end` accepts from `start`, and how many steps away it
+    // is. `None` if the walk goes on for longer than there are (node,
+    // instruction) pairs: it is then going round a loop without an end node.
+    fn walk<'a>(&'a self, start: &'a Node, is_end: impl Fn(&Node) -> bool) -> Option<(&'a Node, usize)> {
+        let limit = self.nodes.len() * self.instructions.len();
+
+        let mut current_node = start;
+        let mut number_of_steps = 0;
+        while !is_end(current_node) {
+            if number_of_steps >= limit {
+                return None;
+            }
+            let instruction = &self.instructions[number_of_steps % self.instructions.len()];
+            let next_node_id = current_node.mappings.get(instruction).unwrap();
+
+            current_node = self.nodes.get(next_node_id).unwrap();
+            number_of_steps += 1;
+        }
+
+        Some((current_node, number_of_steps))
+    }
+
+    pub fn step_until_node(&self, start: &str, end: &str) -> Result<usize, SolveError> {
+        let start_node = self.nodes.get(start).ok_or_else(|| SolveError::new(&format!("no `{}` node", start)))?;
+
+        self.walk(start_node, |node| node.id == end)
+            .map(|(_, steps)| steps)
+            .ok_or_else(|| SolveError::new(&format!("`{}` cannot be reached from `{}`", end, start)))
+    }
+
+    // Fails when a walk never ends, or when the walks only meet after more
+    // than `u64::MAX` steps
+    pub fn steps_until_end(&self) -> Result<u64, SolveError> {
+        let start_nodes: Vec<&Node> = self.nodes.values().filter(|n| n.id.ends_with('A')).collect();
+        // Without walkers, they all stand on an end node from the start, as
+        // the reference finds: `lcm_of` would say 1
+        if start_nodes.is_empty() {
+            return Ok(0);
+        }
+
+        // The walks from each start node are independent
+        let number_of_steps: Vec<usize> = start_nodes.into_par_iter().map(|start_node| {
+            let (end_node, steps) = self.walk(start_node, |node| node.id.ends_with('Z'))
+                .ok_or_else(|| SolveError::new(&format!("`{}` never reaches a `..Z` node", start_node.id)))?;
+            event!(Info, "walk", start = start_node.id, end = end_node.id, steps = steps);
+            Ok(steps)
+        }).collect::<Result<_, SolveError>>()?;
+
+        // Every walk loops back to its end node, so they all meet at the least common multiple
+        lcm_of(number_of_steps.into_iter().map(|steps| steps as u64)).ok_or_else(|| SolveError::new("more than u64::MAX steps"))
+    }
+
+    // Walks from every start node at once until they all stand on an end node,
+    // without assuming anything about loops. `None` after `limit` steps.
+    pub fn steps_until_all_end(&self, limit: usize) -> Option<usize> {
+        let mut current_nodes: Vec<&Node> = self.nodes.values().filter(|n| n.id.ends_with('A')).collect();
+
+        for number_of_steps in 0..=limit {
+            if current_nodes.iter().all(|node| node.id.ends_with('Z')) {
+                return Some(number_of_steps);
+            }
+
+            let instruction = &self.instructions[number_of_steps % self.instructions.len()];
+            for current_node in current_nodes.iter_mut() {
+                let next_node_id = current_node.mappings.get(instruction).unwrap();
+                *current_node = self.nodes.get(next_node_id).unwrap();
+            }
+        }
+
+        None
+    }
+}
+
+// The real input needs about 10^13 steps: the reference only handles small networks
+const REFERENCE_STEP_LIMIT: usize = 10_000_000;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Map::try_from(split_input_into_sections(input))
+    }
+
+    fn try_part1(map: &Self::Input) -> Result<Answer, SolveError> {
+        map.step_until_node("AAA", "ZZZ").map(Answer::from)
+    }
+
+    fn try_part2(map: &Self::Input) -> Result<Answer, SolveError> {
+        map.steps_until_end().map(Answer::from)
+    }
+
+    fn reference_part2(map: &Self::Input) -> Option<Answer> {
+        map.steps_until_all_end(REFERENCE_STEP_LIMIT).map(Answer::from)
+    }
+}
@@ -0,0 +1,128 @@
+
+use std::fmt;
+
+use rayon::prelude::*;
+
+use super::solution::{Answer, ParseError, Solution};
+use super::utils::parsing::parse_numbers;
+use super::utils::split_input_into_lines;
+
+#[derive(Debug, PartialEq)]
+pub struct DataReading {
+    data: Vec<i64>,
+}
+
+impl TryFrom<&str> for DataReading {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let data: Vec<i64> = parse_numbers(line, line)?;
+        if data.is_empty() {
+            return Err(ParseError::line(line, "at least one reading"));
+        }
+
+        Ok(Self {
+            data
+        })
+    }
+}
+
+impl fmt::Display for DataReading {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.data.iter().map(i64::to_string).collect::<Vec<_>>().join(" "))
+    }
+}
+
+impl DataReading {
+    // Add layers of differences until one is filled with 0, or down to a
+    // single value, which is then taken as constant
+    fn layers(&self) -> Vec<Vec<i64>> {
+        let mut layers = vec![self.data.clone()];
+        while layers.last().unwrap().len() > 1 && layers.last().unwrap().iter().any(|value| *value != 0) {
+            let mut new_layer = vec![];
+
+            let previous_layer = layers.last().unwrap();
+            for i in 0..(previous_layer.len()-1) {
+                new_layer.push(previous_layer[i+1] - previous_layer[i]);
+            }
+
+            layers.push(new_layer);
+        }
+        layers
+    }
+
+    pub fn next_value(&self) -> i64 {
+        let mut new_value = 0;
+        for layer in self.layers().into_iter().rev() {
+            let last_value = layer.last().unwrap();
+            new_value += last_value;
+        }
+        new_value
+    }
+
+    pub fn previous_value(&self) -> i64 {
+        let mut new_value = 0;
+        for layer in self.layers().into_iter().rev() {
+            let first_value = layer.first().unwrap();
+            new_value = first_value - new_value;
+        }
+        new_value
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SensorReadings {
+    readings: Vec<DataReading>,
+}
+
+impl TryFrom<Vec<String>> for SensorReadings {
+    type Error = ParseError;
+
+    fn try_from(lines: Vec<String>) -> Result<Self, Self::Error> {
+        let readings = lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| DataReading::try_from(line.as_str()).map_err(|error| error.on_line(index)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            readings
+        })
+    }
+}
+
+impl fmt::Display for SensorReadings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.readings.iter().map(DataReading::to_string).collect::<Vec<_>>().join("\n"))
+    }
+}
+
+impl SensorReadings {
+    pub fn next_values(&self) -> Vec<i64> {
+        self.readings.par_iter().map(|r| r.next_value()).collect()
+    }
+
+    pub fn previous_values(&self) -> Vec<i64> {
+        self.readings.par_iter().map(|r| r.previous_value()).collect()
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = SensorReadings;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        SensorReadings::try_from(split_input_into_lines(input))
+    }
+
+    fn part1(readings: &Self::Input) -> Answer {
+        readings.next_values().into_iter().sum::<i64>().into()
+    }
+
+    fn part2(readings: &Self::Input) -> Answer {
+        readings.previous_values().into_iter().sum::<i64>().into()
+    }
+}
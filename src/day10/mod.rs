@@ -0,0 +1,347 @@
+
+use std::fmt;
+
+use super::solution::{Answer, ParseError, Solution, SolveError};
+use super::trace::event;
+use super::utils::geometry::Direction;
+use super::utils::grid::Grid;
+use super::utils::split_input_into_lines;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Tile {
+    Start,
+    Pipe((char, [Direction; 2])),
+    Ground,
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Tile::Start => write!(f, "S"),
+            Tile::Pipe((char, _)) => write!(f, "{}", char),
+            Tile::Ground => write!(f, "."),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+    pub tile: Tile,
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.tile)
+    }
+}
+
+pub struct Mouse<'a> {
+    pub grid: &'a Grid<Point>,
+    visited: Vec<(usize, usize)>,
+    pub position: (usize, usize),
+    pub previous_movement: Option<((usize, usize), Direction)>,
+    number_of_steps: usize,
+}
+
+impl<'a> Mouse<'a> {
+    pub fn new(grid: &'a Grid<Point>, mouse_starting_position: (usize, usize)) -> Self {
+        Self {
+            grid,
+            visited: vec![],
+            position: mouse_starting_position,
+            previous_movement: None,
+            number_of_steps: 0,
+        }
+    }
+
+    fn current_point(&self) -> &Point {
+        &self.grid[self.position]
+    }
+
+    fn point_in_direction(&self, direction: &Direction) -> Option<&Point> {
+        self.grid
+            .offset(self.position, direction.vector())
+            .map(|position| &self.grid[position])
+    }
+
+    fn can_go_from(&self, direction: &Direction) -> bool {
+        match self.current_point().tile {
+            Tile::Start => true,
+            Tile::Pipe((_, opening_directions)) => opening_directions.iter().any(|opening_direction| direction == opening_direction),
+            Tile::Ground => direction == &self.previous_movement.unwrap().1,
+        }
+    }
+
+    fn can_go_towards(&self, direction: &Direction) -> bool {
+        let next_point = self.point_in_direction(direction);
+        next_point.map(|point| match point.tile {
+            Tile::Start | Tile::Ground => true,
+            Tile::Pipe((_, opening_directions)) => opening_directions.iter().any(|opening_direction| *direction == opening_direction.reversed()),
+        })
+        .unwrap_or(false)
+    }
+
+    fn start(&mut self, direction: &Direction) -> bool {
+        if self.can_go_towards(direction) {
+            self.move_towards(*direction);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn step(&mut self) -> bool {
+        for direction in &Direction::ALL {
+            if self.previous_movement.unwrap().1 != direction.reversed() && self.can_go_from(direction) && self.can_go_towards(direction) {
+                self.move_towards(*direction);
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn move_towards(&mut self, direction: Direction) {
+        let (x, y) = {
+            let current_point = self.current_point();
+            (current_point.x, current_point.y)
+        };
+        let (next_x, next_y) = {
+            let next_point = self.point_in_direction(&direction).unwrap();
+            (next_point.x, next_point.y)
+        };
+
+        event!(Debug, "step", from = (x, y), direction = direction, to = (next_x, next_y), tile = self.grid[(next_x, next_y)].tile);
+        self.previous_movement = Some(((x, y), direction));
+        self.position = (next_x, next_y);
+        self.number_of_steps += 1;
+        self.visited.push((next_x, next_y));
+    }
+
+    pub fn farthest_distance(&self) -> usize {
+        self.number_of_steps / 2 + self.number_of_steps % 2
+    }
+
+    // Fails when no direction leads back to `S`
+    pub fn loop_around(&mut self) -> Result<(), SolveError> {
+        let initial_position = self.position;
+        for start_direction in Direction::ALL {
+            self.position = initial_position;
+            self.number_of_steps = 0;
+            self.visited = vec![initial_position];
+
+            if self.start(&start_direction) {
+                let mut current_tile = self.current_point().tile.clone();
+                while current_tile != Tile::Start {
+                    if self.step() {
+                        current_tile = self.current_point().tile.clone();
+                    } else {
+                        break;
+                    }
+                }
+                if current_tile == Tile::Start {
+                    event!(Info, "loop", start_direction = start_direction, length = self.number_of_steps);
+                    return Ok(());
+                }
+            }
+        }
+
+        Err(SolveError::new("no loop through `S`"))
+    }
+
+    // The pipe under `S`, from the first and the last move of the loop
+    fn start_pipe(&self) -> Option<char> {
+        let start = *self.visited.first()?;
+        if self.visited.len() < 3 || self.visited.last() != Some(&start) {
+            return None;
+        }
+        let direction_to = |position: (usize, usize)| {
+            Direction::ALL.into_iter().find(|direction| self.grid.offset(start, direction.vector()) == Some(position))
+        };
+        let first = direction_to(self.visited[1])?;
+        let last = direction_to(self.visited[self.visited.len() - 2])?;
+
+        ['|', '-', 'L', 'J', '7', 'F'].into_iter().find(|char| {
+            let directions = match char {
+                '|' => [Direction::North, Direction::South],
+                '-' => [Direction::West, Direction::East],
+                'L' => [Direction::North, Direction::East],
+                'J' => [Direction::North, Direction::West],
+                '7' => [Direction::West, Direction::South],
+                _ => [Direction::East, Direction::South],
+            };
+            directions == [first, last] || directions == [last, first]
+        })
+    }
+
+    pub fn count_inner_cells(&self) -> usize {
+        let start_pipe = self.start_pipe();
+        let mut inner_cells_count = 0;
+        for line in self.grid.rows() {
+
+            let mut vertical_open = false;
+
+            let mut horizontal_first_direction = None;
+
+            for point in line.iter() {
+                // Pipes of the loop, `S` counting as the pipe it stands for
+                let loop_pipe = match point.tile {
+                    Tile::Start => start_pipe,
+                    Tile::Pipe((char, _)) if self.visited.contains(&(point.x, point.y)) => Some(char),
+                    _ => None,
+                };
+
+                match loop_pipe {
+                    Some('-') => {},
+                    Some('|') => {
+                        vertical_open = !vertical_open;
+                    }
+                    Some('F' | '7') => {
+                        if horizontal_first_direction.is_some() {
+                            if horizontal_first_direction.unwrap() == Direction::North {
+                                vertical_open = !vertical_open;//Crossing
+                            }
+                            horizontal_first_direction = None;
+                        } else {
+                            horizontal_first_direction = Some(Direction::South);
+                        }
+                    }
+                    Some('L' | 'J') => {
+                        if horizontal_first_direction.is_some() {
+                            if horizontal_first_direction.unwrap() == Direction::South {
+                                vertical_open = !vertical_open;//Crossing
+                            }
+                            horizontal_first_direction = None;
+                        } else {
+                            horizontal_first_direction = Some(Direction::North);
+                        }
+                    }
+                    Some(_) => panic!("Unexpected character"),
+                    None => {
+                        if vertical_open {
+                            inner_cells_count += 1;
+                        }
+                    }
+                }
+            }
+        }
+        inner_cells_count
+    }
+
+    // Floods the outside of the loop on a grid of twice the resolution, where
+    // the loop is a wall without gaps between its tiles
+    pub fn count_inner_cells_by_flood_fill(&self) -> usize {
+        let (width, height) = (2 * self.grid.width() + 1, 2 * self.grid.height() + 1);
+        let mut walls = vec![false; width * height];
+        for step in self.visited.windows(2) {
+            let ((x1, y1), (x2, y2)) = (step[0], step[1]);
+            for (x, y) in [(2 * x1 + 1, 2 * y1 + 1), (x1 + x2 + 1, y1 + y2 + 1), (2 * x2 + 1, 2 * y2 + 1)] {
+                walls[y * width + x] = true;
+            }
+        }
+
+        let mut outside = vec![false; width * height];
+        outside[0] = true;
+        let mut queue = vec![(0_usize, 0_usize)];
+        while let Some((x, y)) = queue.pop() {
+            for (x, y) in [(x.wrapping_sub(1), y), (x + 1, y), (x, y.wrapping_sub(1)), (x, y + 1)] {
+                if x < width && y < height && !walls[y * width + x] && !outside[y * width + x] {
+                    outside[y * width + x] = true;
+                    queue.push((x, y));
+                }
+            }
+        }
+
+        self.grid
+            .positions()
+            .filter(|(x, y)| {
+                let index = (2 * y + 1) * width + 2 * x + 1;
+                !walls[index] && !outside[index]
+            })
+            .count()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PipeMaze {
+    pub grid: Grid<Point>,
+    pub start: (usize, usize),
+}
+
+impl fmt::Display for PipeMaze {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+impl TryFrom<&str> for PipeMaze {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut starts = vec![];
+        let grid = Grid::parse(input, "a pipe, `.` or `S`", |(x, y), char| {
+            let tile = match char {
+                '|' => Tile::Pipe(('|', [Direction::North, Direction::South])),
+                '-' => Tile::Pipe(('-', [Direction::West, Direction::East])),
+                'L' => Tile::Pipe(('L', [Direction::North, Direction::East])),
+                'J' => Tile::Pipe(('J', [Direction::North, Direction::West])),
+                '7' => Tile::Pipe(('7', [Direction::West, Direction::South])),
+                'F' => Tile::Pipe(('F', [Direction::East, Direction::South])),
+                '.' => Tile::Ground,
+                'S' => {
+                    starts.push((x, y));
+                    Tile::Start
+                },
+                _ => return None,
+            };
+
+            Some(Point { x, y, tile })
+        })?;
+
+        if let Some(&(x, y)) = starts.get(1) {
+            let line = &split_input_into_lines(input)[y];
+            let column = line.char_indices().nth(x).map(|(start, _)| start).unwrap_or_default();
+            return Err(ParseError::span(line, column, 1, "a single starting position `S`").on_line(y));
+        }
+
+        Ok(PipeMaze {
+            grid,
+            start: *starts.first().ok_or_else(|| ParseError::input("a starting position `S`"))?,
+        })
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = PipeMaze;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        PipeMaze::try_from(input)
+    }
+
+    fn try_part1(maze: &Self::Input) -> Result<Answer, SolveError> {
+        let mut mouse = Mouse::new(&maze.grid, maze.start);
+        mouse.loop_around()?;
+
+        Ok(mouse.farthest_distance().into())
+    }
+
+    fn try_part2(maze: &Self::Input) -> Result<Answer, SolveError> {
+        let mut mouse = Mouse::new(&maze.grid, maze.start);
+        mouse.loop_around()?;
+
+        Ok(mouse.count_inner_cells().into())
+    }
+
+    fn reference_part2(maze: &Self::Input) -> Option<Answer> {
+        let mut mouse = Mouse::new(&maze.grid, maze.start);
+        mouse.loop_around().ok()?;
+
+        Some(mouse.count_inner_cells_by_flood_fill().into())
+    }
+}
@@ -0,0 +1,168 @@
+
+use std::fmt;
+
+use super::solution::{Answer, ParseError, Solution};
+use super::trace::{event, Shown};
+use super::utils::geometry::Point as GridPoint;
+use super::utils::grid::Grid;
+
+#[derive(Clone)]
+pub enum Point {
+    Galaxy,
+    Empty,
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Point::Empty => write!(f, "."),
+            Point::Galaxy => write!(f, "#"),
+        }
+    }
+}
+
+fn is_empty(point: &Point) -> bool {
+    matches!(point, Point::Empty)
+}
+
+fn galaxies(grid: &Grid<Point>) -> Vec<GridPoint> {
+    grid.iter()
+        .filter(|(_, point)| !is_empty(point))
+        .map(|(position, _)| GridPoint::from(position))
+        .collect()
+}
+
+// (original position, expanded position)
+type Galaxy = (GridPoint, GridPoint);
+
+fn new_line(y: usize, galaxies: &mut [Galaxy], factor: usize) {
+    for galaxy in galaxies.iter_mut() {
+        if galaxy.0.y > y {
+            galaxy.1.y += factor - 1;
+        }
+    }
+}
+
+fn new_column(x: usize, galaxies: &mut [Galaxy], factor: usize) {
+    for galaxy in galaxies.iter_mut() {
+        if galaxy.0.x > x {
+            galaxy.1.x += factor - 1;
+        }
+    }
+}
+
+pub fn sum_of_distances_in_expanded_grid(grid: &Grid<Point>) -> usize {
+    let mut grid = grid.clone();
+
+    let mut y = 0;
+    while y < grid.height() {
+        if grid.row(y).iter().all(is_empty) {
+            grid.insert_row(y, vec![Point::Empty; grid.width()]);
+            y += 1;
+        }
+
+        y += 1;
+    }
+
+    let mut x = 0;
+    while x < grid.width() {
+        if grid.column(x).all(is_empty) {
+            grid.insert_column(x, vec![Point::Empty; grid.height()]);
+            x += 1;
+        }
+
+        x += 1;
+    }
+
+    event!(Debug, "expanded_grid", grid = Shown(&grid));
+    let galaxies = galaxies(&grid);
+
+    let mut result = 0;
+    for i in 0..galaxies.len() {
+        for j in (i+1)..galaxies.len() {
+            result += galaxies[i].manhattan_distance(galaxies[j]);
+        }
+    }
+
+    result
+}
+
+// Empty rows and columns count `factor` times, without building the expanded grid
+pub fn sum_of_distances_with_offsets(grid: &Grid<Point>, factor: usize) -> usize {
+    let mut galaxies: Vec<Galaxy> = galaxies(grid).into_iter().map(|position| (position, position)).collect();
+
+    for y in 0..grid.height() {
+        if grid.row(y).iter().all(is_empty) {
+            new_line(y, &mut galaxies, factor);
+        }
+    }
+
+    for x in 0..grid.width() {
+        if grid.column(x).all(is_empty) {
+            new_column(x, &mut galaxies, factor);
+        }
+    }
+
+    let mut result = 0;
+    for i in 0..galaxies.len() {
+        for j in (i+1)..galaxies.len() {
+            result += galaxies[i].1.manhattan_distance(galaxies[j].1);
+        }
+    }
+
+    result
+}
+
+// Adds up every row and column crossed between each pair of galaxies, the
+// empty ones counting `factor` times
+pub fn sum_of_distances_row_by_row(grid: &Grid<Point>, factor: usize) -> usize {
+    let empty_rows: Vec<bool> = (0..grid.height()).map(|y| grid.row(y).iter().all(is_empty)).collect();
+    let empty_columns: Vec<bool> = (0..grid.width()).map(|x| grid.column(x).all(is_empty)).collect();
+    let crossed = |empty: &[bool], from: usize, to: usize| {
+        (from.min(to)..from.max(to)).map(|index| if empty[index] { factor } else { 1 }).sum::<usize>()
+    };
+
+    let galaxies = galaxies(grid);
+    let mut result = 0;
+    for i in 0..galaxies.len() {
+        for j in (i+1)..galaxies.len() {
+            result += crossed(&empty_rows, galaxies[i].y, galaxies[j].y) + crossed(&empty_columns, galaxies[i].x, galaxies[j].x);
+        }
+    }
+
+    result
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Grid<Point>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input, "`.` or `#`", |_, char| {
+            match char {
+                '.' => Some(Point::Empty),
+                '#' => Some(Point::Galaxy),
+                _ => None,
+            }
+        })
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        sum_of_distances_in_expanded_grid(grid).into()
+    }
+
+    fn part2(grid: &Self::Input) -> Answer {
+        sum_of_distances_with_offsets(grid, 1_000_000).into()
+    }
+
+    fn reference_part1(grid: &Self::Input) -> Option<Answer> {
+        Some(sum_of_distances_row_by_row(grid, 2).into())
+    }
+
+    fn reference_part2(grid: &Self::Input) -> Option<Answer> {
+        Some(sum_of_distances_row_by_row(grid, 1_000_000).into())
+    }
+}
@@ -0,0 +1,395 @@
+
+// `generate` writes a random but valid input for a day, shaped like the real
+// ones but as large as asked, to stress the solvers. The same seed always
+// gives the same input.
+
+use std::collections::HashSet;
+
+use super::day06::{Race, Races};
+use super::day07::{Card, InputHand};
+use super::utils::geometry::{Direction, Direction8};
+use super::utils::random::Rng;
+
+pub const DEFAULT_SIZE: usize = 100;
+
+// `None` for days without a generator
+pub fn generate(day: u8, size: usize, seed: u64) -> Option<String> {
+    let mut rng = Rng::new(seed);
+    let size = size.max(1);
+
+    let lines = match day {
+        1 => calibration_document(&mut rng, size),
+        2 => games(&mut rng, size),
+        3 => schematic(&mut rng, size),
+        4 => scratchcards(&mut rng, size),
+        5 => almanac(&mut rng, size),
+        6 => races(&mut rng, size),
+        7 => hands(&mut rng, size),
+        8 => network(&mut rng, size),
+        9 => histories(&mut rng, size),
+        10 => pipe_maze(&mut rng, size),
+        11 => image(&mut rng, size),
+        _ => return None,
+    };
+    Some(lines.join("\n"))
+}
+
+fn join<T: ToString>(values: &[T]) -> String {
+    values.iter().map(T::to_string).collect::<Vec<_>>().join(" ")
+}
+
+fn columns(values: &[usize], width: usize) -> String {
+    values.iter().map(|value| format!("{:>width$}", value)).collect::<Vec<_>>().join(" ")
+}
+
+// Day 1: `size` lines of letters, digits and spelled digits
+
+const SPELLED_DIGITS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+fn calibration_document(rng: &mut Rng, size: usize) -> Vec<String> {
+    (0..size)
+        .map(|_| {
+            let mut line = String::new();
+            for _ in 0..rng.range(2..=8) {
+                match rng.range(0..=2) {
+                    0 => line.push(char::from(b'0' + rng.range(1..=9) as u8)),
+                    1 => line.push_str(SPELLED_DIGITS[rng.range(0..=8)]),
+                    _ => line.push(char::from(b'a' + rng.range(0..=25) as u8)),
+                }
+            }
+            line
+        })
+        .collect()
+}
+
+// Day 2: `size` games of one to six draws
+
+const COLORS: [&str; 3] = ["red", "green", "blue"];
+
+fn games(rng: &mut Rng, size: usize) -> Vec<String> {
+    (1..=size.min(u16::MAX as usize))
+        .map(|id| {
+            let sets: Vec<String> = (0..rng.range(1..=6))
+                .map(|_| {
+                    let mut colors = COLORS;
+                    rng.shuffle(&mut colors);
+                    let count = rng.range(1..=3);
+                    colors[..count]
+                        .iter()
+                        .map(|color| format!("{} {}", rng.range(1..=20), color))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                })
+                .collect();
+            format!("Game {}: {}", id, sets.join("; "))
+        })
+        .collect()
+}
+
+// Day 3: a `size` x `size` schematic
+
+const SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '@', '=', '%', '&', '-'];
+
+fn schematic(rng: &mut Rng, size: usize) -> Vec<String> {
+    (0..size)
+        .map(|_| {
+            let mut line = String::new();
+            while line.len() < size {
+                if rng.one_in(6) {
+                    let digits = rng.range(1..=3).min(size - line.len()) as u32;
+                    line.push_str(&rng.range(10_usize.pow(digits - 1)..=10_usize.pow(digits) - 1).to_string());
+                    // Keep the next number apart
+                    if line.len() < size {
+                        line.push(if rng.one_in(4) { *rng.pick(&SYMBOLS) } else { '.' });
+                    }
+                } else if rng.one_in(8) {
+                    line.push(*rng.pick(&SYMBOLS));
+                } else {
+                    line.push('.');
+                }
+            }
+            line
+        })
+        .collect()
+}
+
+// Day 4: `size` cards of 10 winning numbers and 25 numbers. Most cards match
+// nothing, so the copies won in part 2 do not grow without bounds. The winning
+// numbers are sorted, as cards are displayed.
+
+fn scratchcards(rng: &mut Rng, size: usize) -> Vec<String> {
+    let mut pool: Vec<usize> = (1..=99).collect();
+
+    (1..=size.min(u16::MAX as usize))
+        .map(|id| {
+            rng.shuffle(&mut pool);
+            let (winning, others) = pool.split_at(10);
+            let matching = if rng.range(0..=4) < 3 { 0 } else { rng.range(1..=3) };
+
+            let mut numbers: Vec<usize> = winning[..matching].iter().chain(&others[..25 - matching]).copied().collect();
+            rng.shuffle(&mut numbers);
+            let mut winning = winning.to_vec();
+            winning.sort();
+            format!("Card {:>3}: {} | {}", id, columns(&winning, 2), columns(&numbers, 2))
+        })
+        .collect()
+}
+
+// Day 5: five seed ranges of up to `size` thousand seeds each, and maps that
+// move the 32 bits numbers around
+
+const CATEGORIES: [&str; 8] = ["seed", "soil", "fertilizer", "water", "light", "temperature", "humidity", "location"];
+const ALMANAC_SPACE: usize = 1 << 32;
+
+fn almanac(rng: &mut Rng, size: usize) -> Vec<String> {
+    let mut seeds = vec![];
+    for _ in 0..5 {
+        let length = rng.range(1..=size.saturating_mul(1000).min(ALMANAC_SPACE));
+        seeds.push(rng.range(0..=ALMANAC_SPACE - length));
+        seeds.push(length);
+    }
+
+    let mut lines = vec![format!("seeds: {}", join(&seeds))];
+    for categories in CATEGORIES.windows(2) {
+        lines.push(String::new());
+        lines.push(format!("{}-to-{} map:", categories[0], categories[1]));
+        lines.extend(mappings(rng));
+    }
+    lines
+}
+
+// Cuts the number space into segments and lays them out again in another
+// order, leaving a few of them where they are
+fn mappings(rng: &mut Rng) -> Vec<String> {
+    let mut cuts: Vec<usize> = (0..rng.range(4..=30)).map(|_| rng.range(1..=ALMANAC_SPACE - 1)).collect();
+    cuts.extend([0, ALMANAC_SPACE]);
+    cuts.sort();
+    cuts.dedup();
+
+    let mut segments: Vec<(usize, usize)> = cuts.windows(2).map(|cut| (cut[0], cut[1] - cut[0])).collect();
+    rng.shuffle(&mut segments);
+
+    let mut destination = 0;
+    let mut lines = vec![];
+    for (source, length) in segments {
+        if !rng.one_in(4) {
+            lines.push(format!("{} {} {}", destination, source, length));
+        }
+        destination += length;
+    }
+    lines
+}
+
+// Day 6: `size` races, at most four so that the single race of part 2 still
+// fits in 64 bits
+
+fn races(rng: &mut Rng, size: usize) -> Vec<String> {
+    let races = (0..size.min(4))
+        .map(|_| {
+            let time_ms = rng.range(10..=99);
+            Race { time_ms, record_distance_mm: rng.range(time_ms..=time_ms * time_ms / 4 - 1) }
+        })
+        .collect();
+
+    Races { races }.to_string().lines().map(String::from).collect()
+}
+
+// Day 7: `size` hands with their bids
+
+const CARDS: [char; 13] = ['2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A'];
+
+fn hands(rng: &mut Rng, size: usize) -> Vec<String> {
+    (0..size)
+        .map(|_| {
+            let cards = (0..5).map(|_| Card::from(*rng.pick(&CARDS))).collect();
+            InputHand { cards, bid: rng.range(1..=1000) }.to_string()
+        })
+        .collect()
+}
+
+// Day 8: about `size` nodes, in one loop per `..A` node: at least three of
+// them, and one more every twenty nodes
+
+const NODE_CHARS: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+// A fresh node id ending with `end`, or with neither `A` nor `Z`
+fn node_id(rng: &mut Rng, used: &mut HashSet<String>, end: Option<char>) -> String {
+    loop {
+        let mut id: String = (0..2).map(|_| char::from(*rng.pick(NODE_CHARS))).collect();
+        id.push(end.unwrap_or_else(|| loop {
+            let char = char::from(*rng.pick(NODE_CHARS));
+            if char != 'A' && char != 'Z' {
+                break char;
+            }
+        }));
+
+        if used.insert(id.clone()) {
+            return id;
+        }
+    }
+}
+
+fn network(rng: &mut Rng, size: usize) -> Vec<String> {
+    // Leaves room in the 3 characters ids
+    let size = size.clamp(2, 30_000);
+    let ghosts = (size / 20).clamp(3, 30);
+    let mut used = HashSet::from(["AAA".to_string(), "ZZZ".to_string()]);
+
+    let instructions: String = (0..rng.range(10..=300)).map(|_| if rng.one_in(2) { 'L' } else { 'R' }).collect();
+
+    let mut nodes = vec![];
+    for ghost in 0..ghosts {
+        let (start, end) = if ghost == 0 {
+            ("AAA".to_string(), "ZZZ".to_string())
+        } else {
+            (node_id(rng, &mut used, Some('A')), node_id(rng, &mut used, Some('Z')))
+        };
+
+        let budget = size / ghosts;
+        let length = rng.range(budget / 2..=budget).max(1);
+        let mut path = vec![start];
+        path.extend((1..length).map(|_| node_id(rng, &mut used, None)));
+        path.push(end);
+
+        for step in path.windows(2) {
+            nodes.push(format!("{} = ({}, {})", step[0], step[1], step[1]));
+        }
+        // The end node leads where the start node does, so every walk loops
+        nodes.push(format!("{} = ({}, {})", path[length], path[1], path[1]));
+    }
+    rng.shuffle(&mut nodes);
+
+    [vec![instructions, String::new()], nodes].concat()
+}
+
+// Day 9: `size` histories of 21 values following polynomials of degree at most 5
+
+fn histories(rng: &mut Rng, size: usize) -> Vec<String> {
+    (0..size)
+        .map(|_| {
+            let degree = rng.range(0..=5);
+            let coefficients: Vec<i64> = (0..=degree).map(|_| rng.signed_range(-5..=5)).collect();
+            let offset = rng.signed_range(-10..=10);
+
+            let values: Vec<i64> = (0..21)
+                .map(|x| coefficients.iter().fold(0, |acc, coefficient| acc * (x + offset) + coefficient))
+                .collect();
+            join(&values)
+        })
+        .collect()
+}
+
+// Day 10: a loop around a random blob of `size` x `size` cells, among stray
+// pipes that lead nowhere. The tiles sit on the corners of the cells.
+
+const STRAY_TILES: [char; 8] = ['|', '-', 'L', 'J', '7', 'F', '.', '.'];
+
+// Grows a blob one neighbour at a time, only adding cells that keep it in one
+// piece, without holes and without cells touching by a corner only: its
+// outline is then a single loop. It starts with two cells against the top edge.
+fn blob(rng: &mut Rng, size: usize) -> Vec<bool> {
+    let mut cells = vec![false; size * size];
+    let first = rng.range(0..=size - 2);
+    cells[first] = true;
+    cells[first + 1] = true;
+
+    let mut members = vec![(first as isize, 0), (first as isize + 1, 0)];
+    let target = size * size / 2;
+    for _ in 0..target * 20 {
+        if members.len() >= target {
+            break;
+        }
+
+        let (x, y) = *rng.pick(&members);
+        let step = rng.pick(&Direction::ALL).vector();
+        let (x, y) = (x + step.x, y + step.y);
+        let inside = |x: isize, y: isize| {
+            (0..size as isize).contains(&x) && (0..size as isize).contains(&y) && cells[y as usize * size + x as usize]
+        };
+        if !(0..size as isize).contains(&x) || !(0..size as isize).contains(&y) || inside(x, y) {
+            continue;
+        }
+
+        let ring: Vec<bool> = Direction8::ALL
+            .iter()
+            .map(|direction| inside(x + direction.vector().x, y + direction.vector().y))
+            .collect();
+        // Diagonals are at odd indices
+        let pinched = (1..8).step_by(2).any(|i| ring[i] && !ring[i - 1] && !ring[(i + 1) % 8]);
+        let stretches = (0..8).filter(|&i| ring[i] && !ring[(i + 7) % 8]).count();
+        if !pinched && stretches == 1 {
+            cells[y as usize * size + x as usize] = true;
+            members.push((x, y));
+        }
+    }
+
+    cells
+}
+
+fn pipe_maze(rng: &mut Rng, size: usize) -> Vec<String> {
+    let size = size.max(2);
+    let cells = blob(rng, size);
+    let inside = |x: isize, y: isize| {
+        (0..size as isize).contains(&x) && (0..size as isize).contains(&y) && cells[y as usize * size + x as usize]
+    };
+
+    let mut rows: Vec<Vec<char>> = (0..=size).map(|_| (0..=size).map(|_| *rng.pick(&STRAY_TILES)).collect()).collect();
+    let mut outline = vec![];
+    for (y, row) in rows.iter_mut().enumerate() {
+        for (x, tile) in row.iter_mut().enumerate() {
+            // The outline goes along a side of the cell between two cells when
+            // only one of them is in the blob
+            let (x, y) = (x as isize, y as isize);
+            let north = inside(x - 1, y - 1) != inside(x, y - 1);
+            let east = inside(x, y - 1) != inside(x, y);
+            let south = inside(x - 1, y) != inside(x, y);
+            let west = inside(x - 1, y - 1) != inside(x - 1, y);
+
+            *tile = match (north, east, south, west) {
+                (true, false, true, false) => '|',
+                (false, true, false, true) => '-',
+                (true, true, false, false) => 'L',
+                (true, false, false, true) => 'J',
+                (false, false, true, true) => '7',
+                (false, true, true, false) => 'F',
+                (false, false, false, false) => continue,
+                _ => unreachable!("the blob never touches itself by a corner"),
+            };
+            outline.push((x as usize, y as usize));
+        }
+    }
+
+    // `S` may be any tile of the loop, but only the loop may lead into it:
+    // stray pipes opening towards it are cleared
+    let (x, y) = *rng.pick(&outline);
+    rows[y][x] = 'S';
+    let neighbours = [
+        (x.wrapping_sub(1), y, "-LF"),
+        (x + 1, y, "-J7"),
+        (x, y.wrapping_sub(1), "|7F"),
+        (x, y + 1, "|LJ"),
+    ];
+    for (x, y, towards_start) in neighbours {
+        if x <= size && y <= size && !outline.contains(&(x, y)) && towards_start.contains(rows[y][x]) {
+            rows[y][x] = '.';
+        }
+    }
+
+    rows.into_iter().map(|row| row.into_iter().collect()).collect()
+}
+
+// Day 11: a `size` x `size` image with a galaxy every twenty cells or so, and
+// a few empty rows and columns to expand
+
+fn image(rng: &mut Rng, size: usize) -> Vec<String> {
+    let empty_rows: Vec<bool> = (0..size).map(|_| rng.one_in(8)).collect();
+    let empty_columns: Vec<bool> = (0..size).map(|_| rng.one_in(8)).collect();
+
+    (0..size)
+        .map(|y| {
+            (0..size)
+                .map(|x| if !empty_rows[y] && !empty_columns[x] && rng.one_in(20) { '#' } else { '.' })
+                .collect()
+        })
+        .collect()
+}
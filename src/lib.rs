@@ -0,0 +1,33 @@
+// Every day exposes its parsed model and a `DayNN` implementing `Solution`,
+// the runner modules below are what the `aoc2023` binary is built from.
+
+pub mod allocations;
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod cross_check;
+pub mod generate;
+pub mod registry;
+pub mod report;
+pub mod run_all;
+pub mod scaffold;
+pub mod solution;
+pub mod trace;
+pub mod utils;
+pub mod verify;
+pub mod watch;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+
+#[cfg(test)]
+mod tests;
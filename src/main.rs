@@ -1,28 +1,269 @@
-mod utils;
-
-//mod _01;
-//mod _02;
-//mod _03;
-//mod _04;
-//mod _05;
-//mod _06;
-//mod _07;
-//mod _08;
-//mod _09;
-//mod _10;
-//mod _11;
-//mod _12;
-//mod _13;
-//mod _14;
-//mod _15;
-//mod _16;
-//mod _17;
-//mod _18;
-//mod _19;
-//mod _20;
-//mod _21;
-mod _22;
+use std::{env, fs, io::{self, Read, Write}, process, time::Duration};
+
+use aoc2023::answers::input_paths;
+use aoc2023::bench::{bench, print_report, read_baseline, write_baseline, Baseline};
+use aoc2023::cli::{parse_args, BenchArgs, Command, GenerateArgs, InputSource, RunAllArgs, RunArgs, Selection, TraceArgs, WatchArgs, USAGE};
+use aoc2023::cross_check::cross_check_all;
+use aoc2023::generate::generate;
+use aoc2023::registry::{select, Solver, SOLVERS};
+use aoc2023::report::{print_records, Format, Record};
+use aoc2023::run_all::run_all;
+use aoc2023::scaffold;
+use aoc2023::trace;
+use aoc2023::verify::verify;
+use aoc2023::watch::watch;
+
+fn selected_solvers(selection: Option<&Selection>) -> Vec<&'static Solver> {
+    let solvers = match selection {
+        Some(selection) => select(selection),
+        None => SOLVERS.iter().collect(),
+    };
+
+    if solvers.is_empty() {
+        eprintln!("No solver registered for {}", selection.map(Selection::to_string).unwrap_or_default());
+        process::exit(2);
+    }
+    solvers
+}
+
+// Sizes the pool shared by run-all and by the parallel loops inside solvers
+fn configure_threads(threads: Option<usize>) {
+    if let Some(threads) = threads {
+        if let Err(error) = rayon::ThreadPoolBuilder::new().num_threads(threads).build_global() {
+            eprintln!("Could not start {} threads: {}", threads, error);
+            process::exit(1);
+        }
+    }
+}
+
+fn configure_trace(args: &TraceArgs) {
+    if let Some(level) = args.level {
+        if let Err(error) = trace::enable(level, args.file.as_deref()) {
+            eprintln!("Could not create trace file: {}", error);
+            process::exit(1);
+        }
+    }
+}
+
+fn read_input(solver: &Solver, source: &InputSource, stdin: &Option<String>) -> io::Result<String> {
+    match source {
+        InputSource::Default => fs::read_to_string(solver.default_input_path()),
+        InputSource::File(path) => fs::read_to_string(path),
+        InputSource::Stdin => Ok(stdin.clone().unwrap_or_default()),
+    }
+}
+
+fn input_label(solver: &Solver, source: &InputSource) -> String {
+    match source {
+        InputSource::Default => solver.default_input_path().display().to_string(),
+        InputSource::File(path) => path.display().to_string(),
+        InputSource::Stdin => "-".to_string(),
+    }
+}
+
+fn read_inputs(solvers: &[&Solver], source: &InputSource) -> Vec<String> {
+    // Stdin can only be consumed once, so it is shared by every selected part
+    let stdin = match source {
+        InputSource::Stdin => {
+            let mut buffer = String::new();
+            if let Err(error) = io::stdin().read_to_string(&mut buffer) {
+                eprintln!("Could not read stdin: {}", error);
+                process::exit(1);
+            }
+            Some(buffer)
+        }
+        _ => None,
+    };
+
+    solvers
+        .iter()
+        .map(|solver| match read_input(solver, source, &stdin) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("Could not read input for day {} part {}: {}", solver.day, solver.part, error);
+                process::exit(1);
+            }
+        })
+        .collect()
+}
+
+fn run(args: RunArgs) {
+    configure_threads(args.threads);
+    configure_trace(&args.trace);
+    let solvers = selected_solvers(Some(&args.selection));
+    let inputs = read_inputs(&solvers, &args.input);
+
+    if args.cross_check {
+        if !cross_check_all(&solvers, &inputs) {
+            process::exit(1);
+        }
+        return;
+    }
+
+    if args.format != Format::Text {
+        let records: Vec<Record> = solvers
+            .into_iter()
+            .zip(inputs)
+            .map(|(solver, input)| Record::new(solver, input_label(solver, &args.input), (solver.run)(&input)))
+            .collect();
+        print_records(&records, args.format);
+        if records.iter().any(|record| record.result.is_err()) {
+            process::exit(1);
+        }
+        return;
+    }
+
+    for (solver, input) in solvers.into_iter().zip(inputs) {
+        match (solver.run)(&input) {
+            Ok(outcome) => println!("Day {} part {}: {}", solver.day, solver.part, outcome.answer),
+            Err(error) => {
+                eprintln!("{}", error);
+                process::exit(1);
+            }
+        }
+    }
+}
+
+fn run_bench(args: BenchArgs) {
+    configure_threads(args.threads);
+    let solvers = selected_solvers(Some(&args.selection));
+    let inputs = read_inputs(&solvers, &args.input);
+
+    let baseline = match &args.baseline {
+        Some(path) => match read_baseline(path) {
+            Ok(baseline) => baseline,
+            Err(error) => {
+                eprintln!("Could not read baseline {}: {}", path.display(), error);
+                process::exit(1);
+            }
+        },
+        None => Baseline::new(),
+    };
+
+    let mut medians = Baseline::new();
+    for (solver, input) in solvers.into_iter().zip(inputs) {
+        match bench(solver, &input, args.runs, args.warmup) {
+            Ok(result) => {
+                print_report(solver, &result, baseline.get(&(solver.day, solver.part)));
+                medians.insert((solver.day, solver.part), result.medians());
+            }
+            Err(error) => {
+                eprintln!("{}", error);
+                process::exit(1);
+            }
+        }
+        println!();
+    }
+
+    if let Some(path) = &args.save_baseline {
+        // Solvers that were not benchmarked this time keep their previous entry,
+        // but a baseline that cannot be read must not be overwritten
+        let mut saved = match read_baseline(path) {
+            Ok(saved) => saved,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Baseline::new(),
+            Err(error) => {
+                eprintln!("Could not read baseline {}: {}", path.display(), error);
+                process::exit(1);
+            }
+        };
+        saved.extend(medians);
+        if let Err(error) = write_baseline(path, &saved) {
+            eprintln!("Could not write baseline {}: {}", path.display(), error);
+            process::exit(1);
+        }
+        println!("Baseline saved to {}", path.display());
+    }
+}
+
+fn run_all_solvers(args: RunAllArgs) {
+    configure_threads(args.threads);
+    configure_trace(&args.trace);
+    if !run_all(&selected_solvers(args.selection.as_ref()), args.serial, args.format) {
+        process::exit(1);
+    }
+}
+
+fn generate_input(args: GenerateArgs) {
+    let Some(input) = generate(args.day, args.size, args.seed) else {
+        eprintln!("No generator for day {}", args.day);
+        process::exit(2);
+    };
+
+    match &args.output {
+        Some(path) => {
+            if let Err(error) = fs::write(path, &input) {
+                eprintln!("Could not write {}: {}", path.display(), error);
+                process::exit(1);
+            }
+        }
+        None if args.cross_check => {}
+        None => {
+            // A closed pipe, e.g. `| head`, is not worth a panic
+            if let Err(error) = writeln!(io::stdout(), "{}", input) {
+                if error.kind() != io::ErrorKind::BrokenPipe {
+                    eprintln!("Could not write the input: {}", error);
+                    process::exit(1);
+                }
+            }
+        }
+    }
+
+    if args.cross_check {
+        let solvers = selected_solvers(Some(&Selection::Day { day: args.day, part: None }));
+        let inputs = vec![input; solvers.len()];
+        if !cross_check_all(&solvers, &inputs) {
+            process::exit(1);
+        }
+    }
+}
+
+fn watch_inputs(args: WatchArgs) {
+    let solvers = selected_solvers(Some(&args.selection));
+    let paths = match args.input {
+        Some(path) => vec![path],
+        None => {
+            let directory = solvers[0].directory();
+            match input_paths(&directory) {
+                Ok(paths) => paths,
+                Err(error) => {
+                    eprintln!("Could not list the inputs in {}: {}", directory.display(), error);
+                    process::exit(1);
+                }
+            }
+        }
+    };
+
+    watch(&solvers, paths, Duration::from_millis(args.interval_ms))
+}
 
 fn main() {
-    println!("Result: {}", _22::run());
+    match parse_args(env::args().skip(1)) {
+        Ok(Command::Run(args)) => run(args),
+        Ok(Command::Verify(args)) => {
+            configure_trace(&args.trace);
+            if !verify(&selected_solvers(args.selection.as_ref())) {
+                process::exit(1);
+            }
+        }
+        Ok(Command::RunAll(args)) => run_all_solvers(args),
+        Ok(Command::Bench(args)) => run_bench(args),
+        Ok(Command::NewDay(day)) => match scaffold::new_day(day) {
+            Ok(paths) => {
+                for path in paths {
+                    println!("Wrote {}", path.display());
+                }
+            }
+            Err(error) => {
+                eprintln!("Could not create day {}: {}", day, error);
+                process::exit(1);
+            }
+        },
+        Ok(Command::Generate(args)) => generate_input(args),
+        Ok(Command::Watch(args)) => watch_inputs(args),
+        Ok(Command::Help) => println!("{}", USAGE),
+        Err(error) => {
+            eprintln!("{}\n\n{}", error, USAGE);
+            process::exit(2);
+        }
+    }
 }
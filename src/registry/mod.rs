@@ -0,0 +1,64 @@
+
+use std::path::PathBuf;
+
+use super::cli::Selection;
+use super::{day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11};
+use super::solution::{reference_part1, reference_part2, run_part1, run_part2, Answer, Outcome, ParseError, RunError};
+
+pub struct Solver {
+    pub day: u8,
+    pub part: u8,
+    pub run: fn(&str) -> Result<Outcome, RunError>,
+    // `None` for parts without a reference solver
+    pub reference: fn(&str) -> Result<Option<Answer>, ParseError>,
+}
+
+impl Solver {
+    pub fn directory(&self) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(format!("src/day{:02}", self.day))
+    }
+
+    pub fn default_input_path(&self) -> PathBuf {
+        self.directory().join("input.txt")
+    }
+}
+
+// One entry per part, in day order: legacy module `_NN` is `SOLVERS[NN - 1]`
+pub const SOLVERS: &[Solver] = &[
+    Solver { day: 1, part: 1, run: run_part1::<day01::Day01>, reference: reference_part1::<day01::Day01> },
+    Solver { day: 1, part: 2, run: run_part2::<day01::Day01>, reference: reference_part2::<day01::Day01> },
+    Solver { day: 2, part: 1, run: run_part1::<day02::Day02>, reference: reference_part1::<day02::Day02> },
+    Solver { day: 2, part: 2, run: run_part2::<day02::Day02>, reference: reference_part2::<day02::Day02> },
+    Solver { day: 3, part: 1, run: run_part1::<day03::Day03>, reference: reference_part1::<day03::Day03> },
+    Solver { day: 3, part: 2, run: run_part2::<day03::Day03>, reference: reference_part2::<day03::Day03> },
+    Solver { day: 4, part: 1, run: run_part1::<day04::Day04>, reference: reference_part1::<day04::Day04> },
+    Solver { day: 4, part: 2, run: run_part2::<day04::Day04>, reference: reference_part2::<day04::Day04> },
+    Solver { day: 5, part: 1, run: run_part1::<day05::Day05>, reference: reference_part1::<day05::Day05> },
+    Solver { day: 5, part: 2, run: run_part2::<day05::Day05>, reference: reference_part2::<day05::Day05> },
+    Solver { day: 6, part: 1, run: run_part1::<day06::Day06>, reference: reference_part1::<day06::Day06> },
+    Solver { day: 6, part: 2, run: run_part2::<day06::Day06>, reference: reference_part2::<day06::Day06> },
+    Solver { day: 7, part: 1, run: run_part1::<day07::Day07>, reference: reference_part1::<day07::Day07> },
+    Solver { day: 7, part: 2, run: run_part2::<day07::Day07>, reference: reference_part2::<day07::Day07> },
+    Solver { day: 8, part: 1, run: run_part1::<day08::Day08>, reference: reference_part1::<day08::Day08> },
+    Solver { day: 8, part: 2, run: run_part2::<day08::Day08>, reference: reference_part2::<day08::Day08> },
+    Solver { day: 9, part: 1, run: run_part1::<day09::Day09>, reference: reference_part1::<day09::Day09> },
+    Solver { day: 9, part: 2, run: run_part2::<day09::Day09>, reference: reference_part2::<day09::Day09> },
+    Solver { day: 10, part: 1, run: run_part1::<day10::Day10>, reference: reference_part1::<day10::Day10> },
+    Solver { day: 10, part: 2, run: run_part2::<day10::Day10>, reference: reference_part2::<day10::Day10> },
+    Solver { day: 11, part: 1, run: run_part1::<day11::Day11>, reference: reference_part1::<day11::Day11> },
+    Solver { day: 11, part: 2, run: run_part2::<day11::Day11>, reference: reference_part2::<day11::Day11> },
+];
+
+pub fn select(selection: &Selection) -> Vec<&'static Solver> {
+    match *selection {
+        Selection::Module(module) => (module as usize)
+            .checked_sub(1)
+            .and_then(|index| SOLVERS.get(index))
+            .into_iter()
+            .collect(),
+        Selection::Day { day, part } => SOLVERS
+            .iter()
+            .filter(|solver| solver.day == day && part.is_none_or(|part| solver.part == part))
+            .collect(),
+    }
+}
@@ -0,0 +1,173 @@
+
+// Structured results for tools: one record per solver run, printed as a JSON
+// array of objects or as CSV with a header line. Durations are integers in
+// nanoseconds, answers are strings with their type next to them so that big
+// numbers survive any JSON parser. The allocation counts are null, or empty in
+// CSV, unless built with the `count-allocations` feature.
+
+use std::{io, str::FromStr};
+
+use super::allocations::Allocations;
+use super::registry::Solver;
+use super::solution::{Answer, Outcome, RunError, Timings};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("Unknown format: {}", s)),
+        }
+    }
+}
+
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub result: Result<Answer, String>,
+    pub timings: Timings,
+    pub allocations: Option<Allocations>,
+}
+
+impl Record {
+    pub fn new(solver: &Solver, input: String, run: Result<Outcome, RunError>) -> Self {
+        let (result, timings, allocations) = match run {
+            Ok(outcome) => (Ok(outcome.answer), outcome.timings, outcome.allocations),
+            Err(error) => (Err(error.summary()), Timings::default(), None),
+        };
+
+        Record {
+            day: solver.day,
+            part: solver.part,
+            input,
+            result,
+            timings,
+            allocations,
+        }
+    }
+
+    pub fn unreadable(solver: &Solver, input: String, error: io::Error) -> Self {
+        Record {
+            day: solver.day,
+            part: solver.part,
+            input,
+            result: Err(format!("input error: {}", error)),
+            timings: Timings::default(),
+            allocations: None,
+        }
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::from('"');
+    for char in s.chars() {
+        match char {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            char if (char as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", char as u32)),
+            char => escaped.push(char),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+// Allocation count, bytes allocated and peak bytes, `None` when not counted
+fn allocation_fields(record: &Record) -> [Option<String>; 3] {
+    match record.allocations {
+        Some(allocations) => [allocations.count, allocations.bytes, allocations.peak_bytes].map(|value| Some(value.to_string())),
+        None => [None, None, None],
+    }
+}
+
+fn to_json(record: &Record) -> String {
+    let (answer, answer_type, error) = match &record.result {
+        Ok(answer) => (json_string(&answer.to_string()), json_string(answer.type_name()), "null".to_string()),
+        Err(error) => ("null".to_string(), "null".to_string(), json_string(error)),
+    };
+
+    let [allocations, allocated_bytes, peak_bytes] = allocation_fields(record).map(|value| value.unwrap_or_else(|| "null".to_string()));
+
+    format!(
+        "{{\"day\": {}, \"part\": {}, \"input\": {}, \"answer\": {}, \"answer_type\": {}, \"parse_ns\": {}, \"solve_ns\": {}, \"allocations\": {}, \"allocated_bytes\": {}, \"peak_bytes\": {}, \"error\": {}}}",
+        record.day,
+        record.part,
+        json_string(&record.input),
+        answer,
+        answer_type,
+        record.timings.parse.as_nanos(),
+        record.timings.solve.as_nanos(),
+        allocations,
+        allocated_bytes,
+        peak_bytes,
+        error,
+    )
+}
+
+fn to_csv(record: &Record) -> String {
+    let (answer, answer_type, error) = match &record.result {
+        Ok(answer) => (answer.to_string(), answer.type_name(), ""),
+        Err(error) => (String::new(), "", error.as_str()),
+    };
+
+    let [allocations, allocated_bytes, peak_bytes] = allocation_fields(record).map(Option::unwrap_or_default);
+
+    [
+        record.day.to_string(),
+        record.part.to_string(),
+        csv_field(&record.input),
+        csv_field(&answer),
+        answer_type.to_string(),
+        record.timings.parse.as_nanos().to_string(),
+        record.timings.solve.as_nanos().to_string(),
+        allocations,
+        allocated_bytes,
+        peak_bytes,
+        csv_field(error),
+    ]
+    .join(",")
+}
+
+// Text is rendered by each command in its own way
+pub fn print_records(records: &[Record], format: Format) {
+    match format {
+        Format::Text => {}
+        Format::Json => {
+            let objects: Vec<String> = records.iter().map(|record| format!("  {}", to_json(record))).collect();
+            if objects.is_empty() {
+                println!("[]");
+            } else {
+                println!("[\n{}\n]", objects.join(",\n"));
+            }
+        }
+        Format::Csv => {
+            println!("day,part,input,answer,answer_type,parse_ns,solve_ns,allocations,allocated_bytes,peak_bytes,error");
+            for record in records {
+                println!("{}", to_csv(record));
+            }
+        }
+    }
+}
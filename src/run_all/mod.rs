@@ -0,0 +1,101 @@
+
+use std::{cmp::Reverse, fs, time::{Duration, Instant}};
+
+use rayon::prelude::*;
+
+use super::allocations;
+use super::registry::Solver;
+use super::report::{print_records, Format, Record};
+use super::utils::{format_bytes, format_duration};
+
+fn run_solver(solver: &Solver) -> Record {
+    let input_path = solver.default_input_path();
+    match fs::read_to_string(&input_path) {
+        Ok(input) => Record::new(solver, input_path.display().to_string(), (solver.run)(&input)),
+        Err(error) => Record::unreadable(solver, input_path.display().to_string(), error),
+    }
+}
+
+// Returns whether every solver produced an answer
+pub fn run_all(solvers: &[&Solver], serial: bool, format: Format) -> bool {
+    // Solvers running at the same time would count each other's allocations
+    let serial = serial || allocations::ENABLED;
+    let start = Instant::now();
+    let mut rows: Vec<Record> = if serial {
+        solvers.iter().map(|solver| run_solver(solver)).collect()
+    } else {
+        solvers.par_iter().map(|solver| run_solver(solver)).collect()
+    };
+    let wall_time = start.elapsed();
+    rows.sort_by_key(|row| Reverse(row.timings.total()));
+
+    if format != Format::Text {
+        print_records(&rows, format);
+        return rows.iter().all(|row| row.result.is_ok());
+    }
+
+    let answer_width = rows
+        .iter()
+        .map(|row| match &row.result {
+            Ok(answer) => answer.to_string().len(),
+            Err(error) => error.len(),
+        })
+        .chain([6])
+        .max()
+        .unwrap_or_default();
+
+    let memory_header = if allocations::ENABLED {
+        format!("  {:>12}  {:>12}  {:>12}", "Allocations", "Allocated", "Peak")
+    } else {
+        String::new()
+    };
+    println!("Day  Part  {:<answer_width$}  {:>12}  {:>12}  {:>12}{}", "Answer", "Parse", "Solve", "Total", memory_header);
+    for row in rows.iter() {
+        let answer = match &row.result {
+            Ok(answer) => answer.to_string(),
+            Err(error) => error.clone(),
+        };
+        let memory = match row.allocations {
+            Some(allocations) => format!(
+                "  {:>12}  {:>12}  {:>12}",
+                allocations.count,
+                format_bytes(allocations.bytes),
+                format_bytes(allocations.peak_bytes),
+            ),
+            None => String::new(),
+        };
+        println!(
+            "{:>3}  {:>4}  {:<answer_width$}  {:>12}  {:>12}  {:>12}{}",
+            row.day,
+            row.part,
+            answer,
+            format_duration(row.timings.parse),
+            format_duration(row.timings.solve),
+            format_duration(row.timings.total()),
+            memory,
+        );
+    }
+
+    let parse: Duration = rows.iter().map(|row| row.timings.parse).sum();
+    let solve: Duration = rows.iter().map(|row| row.timings.solve).sum();
+    println!(
+        "{:<9}  {:<answer_width$}  {:>12}  {:>12}  {:>12}",
+        "Total",
+        "",
+        format_duration(parse),
+        format_duration(solve),
+        format_duration(parse + solve),
+    );
+    // Solvers running concurrently slow each other down, the wall time shows what is gained
+    println!(
+        "Wall time: {} ({})",
+        format_duration(wall_time),
+        match rayon::current_num_threads() {
+            _ if serial => "serial".to_string(),
+            1 => "1 thread".to_string(),
+            threads => format!("{} threads", threads),
+        },
+    );
+
+    rows.iter().all(|row| row.result.is_ok())
+}
@@ -0,0 +1,152 @@
+
+// `new-day` writes the files of a new day next to the others and registers it
+// in `lib.rs`, the registry and the regression tests, so the next build runs it.
+
+use std::{fs, io, path::{Path, PathBuf}};
+
+use super::registry::SOLVERS;
+
+const MODULE_TEMPLATE: &str = "
+use super::solution::{Answer, ParseError, Solution};
+use super::utils::split_input_into_lines;
+
+pub struct DayNN;
+
+impl Solution for DayNN {
+    const DAY: u8 = N;
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(split_input_into_lines(input))
+    }
+
+    fn part1(_lines: &Self::Input) -> Answer {
+        \"unsolved\".into()
+    }
+
+    fn part2(_lines: &Self::Input) -> Answer {
+        \"unsolved\".into()
+    }
+}
+";
+
+const TESTS_TEMPLATE: &str = "
+#[test]
+#[ignore = \"record the example answers in src/dayNN/example_expected.txt\"]
+fn dayNN_part1_example_input() {
+    check(N, 1, \"example_input.txt\");
+}
+
+#[test]
+#[ignore = \"record the example answers in src/dayNN/example_expected.txt\"]
+fn dayNN_part2_example_input() {
+    check(N, 2, \"example_input.txt\");
+}
+";
+
+fn fill(template: &str, day: u8) -> String {
+    template
+        .replace("NN", &format!("{:02}", day))
+        .replace("N,", &format!("{},", day))
+        .replace("= N;", &format!("= {};", day))
+}
+
+fn src_directory() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src")
+}
+
+fn invalid_data(path: &Path, message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), message))
+}
+
+// Adds `pub mod dayNN;` among the other day modules, keeping them sorted
+pub fn register_module(source: &str, day: u8) -> Option<String> {
+    let module = format!("pub mod day{:02};", day);
+    let mut lines: Vec<&str> = source.lines().collect();
+
+    let days: Vec<usize> = (0..lines.len()).filter(|&index| lines[index].starts_with("pub mod day")).collect();
+    let last = *days.last()?;
+    let index = days
+        .into_iter()
+        .find(|&index| lines[index] > module.as_str())
+        .unwrap_or(last + 1);
+    lines.insert(index, &module);
+
+    Some(lines.join("\n") + "\n")
+}
+
+// Adds the day to the `use super::{day01, ...}` import and its parts to `SOLVERS`
+pub fn register_solvers(source: &str, day: u8) -> Option<String> {
+    let module = format!("day{:02}", day);
+    let mut lines: Vec<String> = source.lines().map(String::from).collect();
+
+    let import = lines.iter().position(|line| line.starts_with("use super::{day01"))?;
+    let mut modules: Vec<&str> = lines[import]
+        .strip_prefix("use super::{")?
+        .strip_suffix("};")?
+        .split(", ")
+        .collect();
+    modules.push(&module);
+    modules.sort();
+    lines[import] = format!("use super::{{{}}};", modules.join(", "));
+
+    let start = lines.iter().position(|line| line.starts_with("pub const SOLVERS"))?;
+    let end = start + lines[start..].iter().position(|line| line == "];")?;
+    let index = (start + 1..end)
+        .find(|&index| {
+            lines[index]
+                .trim_start()
+                .strip_prefix("Solver { day: ")
+                .and_then(|rest| rest.split(',').next())
+                .and_then(|other| other.parse::<u8>().ok())
+                .is_some_and(|other| other > day)
+        })
+        .unwrap_or(end);
+    for part in [2, 1] {
+        lines.insert(
+            index,
+            format!(
+                "    Solver {{ day: {}, part: {}, run: run_part{}::<{}::Day{:02}>, reference: reference_part{}::<{}::Day{:02}> }},",
+                day, part, part, module, day, part, module, day,
+            ),
+        );
+    }
+
+    Some(lines.join("\n") + "\n")
+}
+
+// Returns the created and updated files
+pub fn new_day(day: u8) -> io::Result<Vec<PathBuf>> {
+    let src = src_directory();
+    let directory = src.join(format!("day{:02}", day));
+    if SOLVERS.iter().any(|solver| solver.day == day) || directory.exists() {
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("day {} already exists", day)));
+    }
+
+    // Prepare every edit before writing anything, so a failure leaves the tree untouched
+    let lib_path = src.join("lib.rs");
+    let lib = register_module(&fs::read_to_string(&lib_path)?, day)
+        .ok_or_else(|| invalid_data(&lib_path, "no `pub mod dayNN;` declarations"))?;
+    let registry_path = src.join("registry/mod.rs");
+    let registry = register_solvers(&fs::read_to_string(&registry_path)?, day)
+        .ok_or_else(|| invalid_data(&registry_path, "no day import or `SOLVERS` list"))?;
+    let tests_path = src.join("tests/mod.rs");
+    let tests = fs::read_to_string(&tests_path)? + &fill(TESTS_TEMPLATE, day);
+
+    fs::create_dir(&directory)?;
+    let files = [
+        (directory.join("mod.rs"), fill(MODULE_TEMPLATE, day)),
+        (directory.join("input.txt"), String::new()),
+        (directory.join("example_input.txt"), String::new()),
+        (directory.join("example_expected.txt"), "part1: -\npart2: -\n".to_string()),
+        (lib_path, lib),
+        (registry_path, registry),
+        (tests_path, tests),
+    ];
+    for (path, content) in files.iter() {
+        fs::write(path, content)?;
+    }
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
@@ -0,0 +1,339 @@
+
+use std::{fmt, time::{Duration, Instant}};
+
+use super::allocations::{measure, Allocations};
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Big(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsigned(value) => write!(f, "{}", value),
+            Answer::Signed(value) => write!(f, "{}", value),
+            Answer::Big(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl Answer {
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Answer::Unsigned(_) => "unsigned",
+            Answer::Signed(_) => "signed",
+            Answer::Big(_) => "big",
+            Answer::Text(_) => "text",
+        }
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Unsigned(value as u64)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Unsigned(value)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Signed(value)
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        Answer::Big(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+// A parse failure located in the puzzle input, rendered like a compiler diagnostic
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    pub day: Option<u8>,
+    // 1-based, `None` when the error is about the input as a whole
+    pub line: Option<usize>,
+    // 1-based, in characters
+    pub column: usize,
+    pub length: usize,
+    pub text: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn input(expected: &str) -> Self {
+        ParseError {
+            day: None,
+            line: None,
+            column: 1,
+            length: 0,
+            text: String::new(),
+            expected: expected.to_string(),
+        }
+    }
+
+    // Errors about a single line start on line 1 and are moved to their
+    // actual line by the caller with `on_line`
+    pub fn line(text: &str, expected: &str) -> Self {
+        Self::span(text, 0, text.len(), expected)
+    }
+
+    // `start` and `length` are byte offsets in `text`, e.g. from a regex match
+    pub fn span(text: &str, start: usize, length: usize, expected: &str) -> Self {
+        let start = start.min(text.len());
+        let end = (start + length).min(text.len());
+
+        ParseError {
+            day: None,
+            line: Some(1),
+            column: text[..start].chars().count() + 1,
+            length: text[start..end].chars().count(),
+            text: text.to_string(),
+            expected: expected.to_string(),
+        }
+    }
+
+    // Error about `part`, which must be a slice of `text`
+    pub fn part(text: &str, part: &str, expected: &str) -> Self {
+        let start = (part.as_ptr() as usize).saturating_sub(text.as_ptr() as usize);
+        Self::span(text, start, part.len(), expected)
+    }
+
+    // Moves the error down by `line_index` lines: a single line error lands
+    // on that line (0-based, as given by `enumerate`), and an error inside a
+    // block of lines is moved by the index of the block's first line
+    pub fn on_line(mut self, line_index: usize) -> Self {
+        self.line = self.line.map(|line| line + line_index);
+        self
+    }
+
+    pub fn with_day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    pub fn found(&self) -> String {
+        self.text.chars().skip(self.column - 1).take(self.length).collect()
+    }
+
+    // One line version, for tables and reports
+    pub fn summary(&self) -> String {
+        let found = match self.found() {
+            found if found.is_empty() => "nothing".to_string(),
+            found => format!("`{}`", found),
+        };
+        match self.line {
+            Some(line) => format!("line {}, column {}: expected {}, found {}", line, self.column, self.expected, found),
+            None => format!("expected {}", self.expected),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let day = self.day.map(|day| format!("day {:02}", day)).unwrap_or_else(|| "input".to_string());
+        let line = match self.line {
+            Some(line) => line,
+            None => {
+                writeln!(f, "error: expected {}", self.expected)?;
+                return write!(f, " --> {}", day);
+            }
+        };
+
+        let found = self.found();
+        if found.is_empty() {
+            writeln!(f, "error: expected {}", self.expected)?;
+        } else {
+            writeln!(f, "error: expected {}, found `{}`", self.expected, found)?;
+        }
+
+        let gutter = " ".repeat(line.to_string().len());
+        writeln!(f, "{} --> {}, line {}, column {}", gutter, day, line, self.column)?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", line, self.text)?;
+        write!(f, "{} | {}{}", gutter, " ".repeat(self.column - 1), "^".repeat(self.length.max(1)))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Why a well formed input has no answer, e.g. a map where the end cannot be
+// reached or an answer too large for its type
+#[derive(Clone, Debug, PartialEq)]
+pub struct SolveError {
+    pub day: Option<u8>,
+    pub message: String,
+}
+
+impl SolveError {
+    pub fn new(message: &str) -> Self {
+        SolveError { day: None, message: message.to_string() }
+    }
+
+    pub fn with_day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let day = self.day.map(|day| format!("day {:02}", day)).unwrap_or_else(|| "input".to_string());
+        writeln!(f, "error: {}", self.message)?;
+        write!(f, " --> {}", day)
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+// Either way a run can end without an answer
+#[derive(Clone, Debug, PartialEq)]
+pub enum RunError {
+    Parse(ParseError),
+    Solve(SolveError),
+}
+
+impl RunError {
+    // One line version, for tables and reports
+    pub fn summary(&self) -> String {
+        match self {
+            RunError::Parse(error) => format!("parse error: {}", error.summary()),
+            RunError::Solve(error) => format!("solve error: {}", error.message),
+        }
+    }
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunError::Parse(error) => write!(f, "{}", error),
+            RunError::Solve(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for RunError {}
+
+impl From<ParseError> for RunError {
+    fn from(error: ParseError) -> Self {
+        RunError::Parse(error)
+    }
+}
+
+impl From<SolveError> for RunError {
+    fn from(error: SolveError) -> Self {
+        RunError::Solve(error)
+    }
+}
+
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    // A day implements either `partN`, or `try_partN` when some well formed
+    // inputs have no answer. The runners only call `try_partN`.
+    fn part1(input: &Self::Input) -> Answer {
+        Self::try_part1(input).unwrap_or_else(|error| panic!("{}", error.message))
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        Self::try_part2(input).unwrap_or_else(|error| panic!("{}", error.message))
+    }
+
+    fn try_part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(Self::part1(input))
+    }
+
+    fn try_part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(Self::part2(input))
+    }
+
+    // Slow but straightforward versions of the parts, that `--cross-check`
+    // compares the answers against. `None` when there is none, or when it
+    // gives up on a large input.
+    fn reference_part1(_input: &Self::Input) -> Option<Answer> {
+        None
+    }
+
+    fn reference_part2(_input: &Self::Input) -> Option<Answer> {
+        None
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Timings {
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.solve
+    }
+}
+
+#[derive(Debug)]
+pub struct Outcome {
+    pub answer: Answer,
+    pub timings: Timings,
+    // Over parsing and solving, `None` unless allocations are counted
+    pub allocations: Option<Allocations>,
+}
+
+fn run_timed<S: Solution>(input: &str, solve: fn(&S::Input) -> Result<Answer, SolveError>) -> Result<Outcome, RunError> {
+    let (outcome, allocations) = measure(|| {
+        let start = Instant::now();
+        let parsed = S::parse(input).map_err(|error| error.with_day(S::DAY))?;
+        let parse_time = start.elapsed();
+
+        let start = Instant::now();
+        let answer = solve(&parsed).map_err(|error| error.with_day(S::DAY))?;
+        let solve_time = start.elapsed();
+
+        Ok::<_, RunError>((answer, Timings { parse: parse_time, solve: solve_time }))
+    });
+    let (answer, timings) = outcome?;
+
+    Ok(Outcome { answer, timings, allocations })
+}
+
+pub fn run_part1<S: Solution>(input: &str) -> Result<Outcome, RunError> {
+    run_timed::<S>(input, S::try_part1)
+}
+
+pub fn run_part2<S: Solution>(input: &str) -> Result<Outcome, RunError> {
+    run_timed::<S>(input, S::try_part2)
+}
+
+pub fn reference_part1<S: Solution>(input: &str) -> Result<Option<Answer>, ParseError> {
+    S::parse(input).map(|parsed| S::reference_part1(&parsed)).map_err(|error| error.with_day(S::DAY))
+}
+
+pub fn reference_part2<S: Solution>(input: &str) -> Result<Option<Answer>, ParseError> {
+    S::parse(input).map(|parsed| S::reference_part2(&parsed)).map_err(|error| error.with_day(S::DAY))
+}
@@ -0,0 +1,885 @@
+
+// Regression tests over the recorded answers, see `answers` for the file format
+
+use std::{cmp::Ordering, fmt, fs, panic, time::Duration};
+
+use super::allocations::{measure, ENABLED};
+use super::answers::{expected_answer, input_paths};
+use super::bench::{read_baseline, write_baseline, Baseline, Medians};
+use super::cross_check::{cross_check, Verdict};
+use super::day02::Day02;
+use super::day04::Day04;
+use super::day05::{Day05, Map};
+use super::day06::Day06;
+use super::day07::{compare_hands, Day07, InputHand, TypedHand};
+use super::day08::Day08;
+use super::day09::{DataReading, Day09};
+use super::day10::Day10;
+use super::day11::{sum_of_distances_in_expanded_grid, sum_of_distances_with_offsets, Day11};
+use super::generate::generate;
+use super::registry::{Solver, SOLVERS};
+use super::report::Record;
+use super::scaffold::{register_module, register_solvers};
+use super::solution::{Answer, Outcome, ParseError, RunError, Solution, SolveError, Timings};
+use super::trace::{format_event, Level, Shown};
+use super::utils::geometry::{Direction, Direction8, Point, SignedPoint, Vector};
+use super::utils::format_bytes;
+use super::utils::grid::Grid;
+use super::utils::math::{binomial, crt, gcd_of, isqrt, lcm_of, mod_inverse, mod_pow};
+use super::utils::panics::quietly;
+use super::utils::parsing::{parse_numbers, split_once, strip_literal};
+use super::utils::property::{self, find_counterexample, integers, strings, vectors};
+use super::watch::Watcher;
+
+fn solver(day: u8, part: u8) -> &'static Solver {
+    SOLVERS.iter().find(|solver| solver.day == day && solver.part == part).unwrap()
+}
+
+fn check(day: u8, part: u8, input_name: &str) {
+    let solver = solver(day, part);
+    let input_path = solver.directory().join(input_name);
+
+    let input = fs::read_to_string(&input_path).unwrap();
+    let expected = expected_answer(&input_path, part).unwrap().unwrap();
+    let answer = (solver.run)(&input).unwrap().answer;
+
+    assert_eq!(answer.to_string(), expected, "day {} part {} on {}", day, part, input_name);
+}
+
+// Same as `check`, on a copy of the input saved by a Windows editor
+fn check_crlf(day: u8, part: u8, input_name: &str) {
+    let solver = solver(day, part);
+    let input_path = solver.directory().join(input_name);
+
+    let input = fs::read_to_string(&input_path).unwrap().replace('\n', "\r\n") + "\r\n\r\n";
+    let expected = expected_answer(&input_path, part).unwrap().unwrap();
+    let answer = (solver.run)(&input).unwrap().answer;
+
+    assert_eq!(answer.to_string(), expected, "day {} part {} on {} with CRLF", day, part, input_name);
+}
+
+fn parse_error(day: u8, input: &str) -> ParseError {
+    let solver = solver(day, 1);
+    match (solver.run)(input) {
+        Err(RunError::Parse(error)) => error,
+        run => panic!("expected a parse error, got {:?}", run),
+    }
+}
+
+fn solve_error(day: u8, part: u8, input: &str) -> SolveError {
+    let solver = solver(day, part);
+    match (solver.run)(input) {
+        Err(RunError::Solve(error)) => error,
+        run => panic!("expected a solve error, got {:?}", run),
+    }
+}
+
+#[test]
+fn day02_part1_example_input() {
+    check(2, 1, "example_input.txt");
+}
+
+#[test]
+fn day02_part2_example_input() {
+    check(2, 2, "example_input.txt");
+}
+
+#[test]
+fn day03_part1_example_input() {
+    check(3, 1, "example_input.txt");
+}
+
+#[test]
+fn day03_part2_example_input() {
+    check(3, 2, "example_input.txt");
+}
+
+#[test]
+fn day04_part1_example_input() {
+    check(4, 1, "example_input.txt");
+}
+
+#[test]
+fn day04_part2_example_input() {
+    check(4, 2, "example_input.txt");
+}
+
+#[test]
+fn day05_part1_example_input() {
+    check(5, 1, "example_input.txt");
+}
+
+#[test]
+fn day05_part2_example_input() {
+    check(5, 2, "example_input.txt");
+}
+
+#[test]
+fn day06_part1_example_input() {
+    check(6, 1, "example_input.txt");
+}
+
+#[test]
+fn day06_part2_example_input() {
+    check(6, 2, "example_input.txt");
+}
+
+#[test]
+fn day07_part1_example_input() {
+    check(7, 1, "example_input.txt");
+}
+
+#[test]
+fn day07_part2_example_input() {
+    check(7, 2, "example_input.txt");
+}
+
+#[test]
+fn day08_part1_example_input() {
+    check(8, 1, "example_input.txt");
+}
+
+#[test]
+fn day08_part2_example_input_part2() {
+    check(8, 2, "example_input_part2.txt");
+}
+
+#[test]
+fn day09_part1_example_input() {
+    check(9, 1, "example_input.txt");
+}
+
+#[test]
+fn day09_part2_example_input() {
+    check(9, 2, "example_input.txt");
+}
+
+#[test]
+fn day10_part1_example_input() {
+    check(10, 1, "example_input.txt");
+}
+
+#[test]
+fn day10_part2_example_input_part2() {
+    check(10, 2, "example_input_part2.txt");
+}
+
+#[test]
+fn day11_part1_example_input() {
+    check(11, 1, "example_input.txt");
+}
+
+#[test]
+fn day11_part2_example_input() {
+    check(11, 2, "example_input.txt");
+}
+
+#[test]
+fn day01_part1_input() {
+    check(1, 1, "input.txt");
+}
+
+#[test]
+fn day01_part2_input() {
+    check(1, 2, "input.txt");
+}
+
+#[test]
+fn day02_part1_input() {
+    check(2, 1, "input.txt");
+}
+
+#[test]
+fn day02_part2_input() {
+    check(2, 2, "input.txt");
+}
+
+#[test]
+fn day03_part1_input() {
+    check(3, 1, "input.txt");
+}
+
+#[test]
+fn day03_part2_input() {
+    check(3, 2, "input.txt");
+}
+
+#[test]
+fn day04_part1_input() {
+    check(4, 1, "input.txt");
+}
+
+#[test]
+fn day04_part2_input() {
+    check(4, 2, "input.txt");
+}
+
+#[test]
+fn day05_part1_input() {
+    check(5, 1, "input.txt");
+}
+
+#[test]
+#[ignore = "brute-forces every seed: run with `cargo test --release -- --ignored`"]
+fn day05_part2_input() {
+    check(5, 2, "input.txt");
+}
+
+#[test]
+fn day06_part1_input() {
+    check(6, 1, "input.txt");
+}
+
+#[test]
+fn day06_part2_input() {
+    check(6, 2, "input.txt");
+}
+
+#[test]
+fn day07_part1_input() {
+    check(7, 1, "input.txt");
+}
+
+#[test]
+fn day07_part2_input() {
+    check(7, 2, "input.txt");
+}
+
+#[test]
+fn day08_part1_input() {
+    check(8, 1, "input.txt");
+}
+
+#[test]
+fn day08_part2_input() {
+    check(8, 2, "input.txt");
+}
+
+#[test]
+fn day09_part1_input() {
+    check(9, 1, "input.txt");
+}
+
+#[test]
+fn day09_part2_input() {
+    check(9, 2, "input.txt");
+}
+
+#[test]
+fn day10_part1_input() {
+    check(10, 1, "input.txt");
+}
+
+#[test]
+fn day10_part2_input() {
+    check(10, 2, "input.txt");
+}
+
+#[test]
+fn day11_part1_input() {
+    check(11, 1, "input.txt");
+}
+
+#[test]
+fn day11_part2_input() {
+    check(11, 2, "input.txt");
+}
+
+#[test]
+fn parse_error_points_at_the_bad_line() {
+    let error = parse_error(7, "32T3K 765\nT55J5 684\nKK677 x");
+
+    assert_eq!(error.day, Some(7));
+    assert_eq!(error.line, Some(3));
+    assert_eq!(error.found(), "KK677 x");
+}
+
+#[test]
+fn parse_error_points_at_the_bad_character() {
+    let error = parse_error(11, "...#\n.#.?");
+
+    assert_eq!(error.line, Some(2));
+    assert_eq!(error.column, 4);
+    assert_eq!(error.found(), "?");
+}
+
+#[test]
+fn parse_error_points_inside_a_section() {
+    let error = parse_error(5, "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n\nsoil-to-fertilizer map:\n0 15");
+
+    assert_eq!(error.line, Some(7));
+    assert_eq!(error.found(), "0 15");
+}
+
+#[test]
+fn parse_error_for_odd_number_of_seeds() {
+    let error = parse_error(5, "seeds: 79 14 55\n\nseed-to-soil map:\n50 98 2");
+
+    assert_eq!(error.line, Some(1));
+    assert_eq!(error.column, 16);
+    assert_eq!(error.expected, "a length after the last range start");
+}
+
+#[test]
+fn parse_error_for_missing_start() {
+    let error = parse_error(10, "..\n..");
+
+    assert_eq!(error.line, None);
+    assert_eq!(error.to_string(), "error: expected a starting position `S`\n --> day 10");
+}
+
+#[test]
+fn parse_error_for_second_start() {
+    let error = parse_error(10, "S-7\n|.|\nL-S");
+
+    assert_eq!((error.line, error.column), (Some(3), 3));
+    assert_eq!(error.expected, "a single starting position `S`");
+}
+
+#[test]
+fn day10_start_outside_a_loop_is_reported() {
+    assert_eq!(solve_error(10, 1, "S..\n...\n...").message, "no loop through `S`");
+    assert_eq!(solve_error(10, 2, "S..\n...\n...").message, "no loop through `S`");
+}
+
+#[test]
+fn new_day_module_is_declared_in_order() {
+    let lib = register_module(include_str!("../lib.rs"), 12).unwrap();
+
+    assert!(lib.contains("pub mod day11;\npub mod day12;\n"));
+}
+
+#[test]
+fn new_day_solvers_are_registered_in_order() {
+    let registry = register_solvers("use super::{day01, day03};\n\npub const SOLVERS: &[Solver] = &[\n    Solver { day: 1, part: 1, run: run_part1::<day01::Day01> },\n    Solver { day: 3, part: 1, run: run_part1::<day03::Day03> },\n];\n", 2).unwrap();
+
+    assert!(registry.starts_with("use super::{day01, day02, day03};\n"));
+    assert!(registry.contains("Day01> },\n    Solver { day: 2, part: 1, run: run_part1::<day02::Day02>, reference: reference_part1::<day02::Day02> },\n    Solver { day: 2, part: 2, run: run_part2::<day02::Day02>, reference: reference_part2::<day02::Day02> },\n    Solver { day: 3,"));
+}
+
+#[test]
+fn day05_part1_example_input_crlf() {
+    check_crlf(5, 1, "example_input.txt");
+}
+
+#[test]
+fn day07_part2_example_input_crlf() {
+    check_crlf(7, 2, "example_input.txt");
+}
+
+#[test]
+fn day08_part2_example_input_crlf() {
+    check_crlf(8, 2, "example_input_part2.txt");
+}
+
+#[test]
+fn day10_part2_example_input_crlf() {
+    check_crlf(10, 2, "example_input_part2.txt");
+}
+
+#[test]
+fn day11_part1_example_input_crlf() {
+    check_crlf(11, 1, "example_input.txt");
+}
+
+#[test]
+fn grid_neighbours_stay_inside() {
+    let grid = Grid::parse("abc\ndef", "a letter", |_, char| Some(char)).unwrap();
+
+    assert_eq!(grid.neighbours4((0, 0)).collect::<Vec<_>>(), vec![(1, 0), (0, 1)]);
+    assert_eq!(grid.neighbours8((2, 1)).map(|position| grid[position]).collect::<String>(), "ceb");
+    assert_eq!(grid.get((3, 0)), None);
+}
+
+#[test]
+fn grid_rows_and_columns_can_be_inserted() {
+    let mut grid = Grid::parse("ab\ncd", "a letter", |_, char| Some(char)).unwrap();
+    grid.insert_row(1, vec!['x', 'y']);
+    grid.insert_column(2, vec!['1', '2', '3']);
+
+    assert_eq!(grid.to_string(), "ab1\nxy2\ncd3");
+    assert_eq!(grid.column(1).collect::<String>(), "byd");
+}
+
+#[test]
+fn grid_rejects_ragged_lines() {
+    let error = Grid::parse("ab\nc", "a letter", |_, char| Some(char)).err().unwrap();
+
+    assert_eq!(error.line, Some(2));
+}
+
+#[test]
+fn geometry_directions_turn_clockwise() {
+    assert_eq!(Direction::North.turn_right(), Direction::East);
+    assert_eq!(Direction::North.turn_left(), Direction::West);
+    assert_eq!(Direction::East.reversed(), Direction::West);
+    assert_eq!(Direction8::NorthWest.turn_right(), Direction8::North);
+    assert_eq!(Direction8::from(Direction::South).reversed().vector(), Direction::North.vector());
+}
+
+#[test]
+fn geometry_points_move_and_measure() {
+    let point = Point::new(1, 2);
+
+    assert_eq!(point.checked_add(Vector::new(-2, 0)), None);
+    assert_eq!(point.checked_add(Direction::North.vector() * 2), Some(Point::new(1, 0)));
+    assert_eq!(point.manhattan_distance(Point::new(4, 0)), 5);
+    assert_eq!(point.chebyshev_distance(Point::new(4, 0)), 3);
+    assert_eq!(Point::try_from(SignedPoint::new(-1, 0)), Err(SignedPoint::new(-1, 0)));
+}
+
+#[test]
+fn math_lcm_detects_overflow() {
+    assert_eq!(lcm_of([4, 6, 10]), Some(60));
+    assert_eq!(lcm_of([]), Some(1));
+    assert_eq!(lcm_of([u64::MAX, u64::MAX - 1]), None);
+    assert_eq!(gcd_of([12, 18, 30]), 6);
+}
+
+#[test]
+fn math_crt_handles_non_coprime_moduli() {
+    assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+    assert_eq!(crt([(2, 4), (4, 6)]), Some((10, 12)));
+    assert_eq!(crt([(1, 4), (2, 6)]), None);
+    assert_eq!(mod_inverse(3, 7), Some(5));
+    assert_eq!(mod_pow(3, 200, 13), 9);
+}
+
+#[test]
+fn math_isqrt_and_binomial() {
+    assert_eq!(isqrt(99), 9);
+    assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+    assert_eq!(binomial(5, 2), Some(10));
+    assert_eq!(binomial(62, 31), Some(465428353255261088));
+    assert_eq!(binomial(100, 50), None);
+}
+
+#[test]
+fn parsing_combinators_point_into_the_line() {
+    let line = "Time: 7 15 x";
+    let numbers = strip_literal(line, line, "Time:").unwrap();
+
+    assert_eq!(parse_numbers::<u32>(line, " 7 15").unwrap(), vec![7, 15]);
+    assert_eq!(parse_numbers::<u32>(line, numbers).err().unwrap().column, 12);
+    assert_eq!(strip_literal(line, line, "Distance:").err().unwrap().found(), "Time: 7 1");
+    assert_eq!(split_once(line, numbers, "15").unwrap(), (" 7 ", " x"));
+}
+
+#[test]
+fn generated_inputs_are_solved() {
+    for seed in 0..3 {
+        for solver in SOLVERS.iter() {
+            // Days added with `new-day` have no generator until one is written
+            let Some(input) = generate(solver.day, 12, seed) else {
+                continue;
+            };
+            assert!((solver.run)(&input).is_ok(), "day {} part {} seed {}", solver.day, solver.part, seed);
+        }
+    }
+}
+
+#[test]
+fn generated_inputs_depend_on_the_seed_only() {
+    for day in 1..=11 {
+        assert_eq!(generate(day, 20, 7), generate(day, 20, 7));
+        assert_ne!(generate(day, 20, 7), generate(day, 20, 8));
+    }
+    assert_eq!(generate(12, 20, 7), None);
+}
+
+#[test]
+fn generated_networks_have_several_start_nodes() {
+    for size in [2, 12, 100] {
+        let input = generate(8, size, 0).unwrap();
+        let start_nodes = input.lines().filter(|line| line.split(" = ").next().is_some_and(|id| id.ends_with('A'))).count();
+        assert!(start_nodes >= 3, "size {}: {} start nodes", size, start_nodes);
+    }
+}
+
+#[test]
+fn cross_check_references_agree_on_generated_inputs() {
+    for solver in SOLVERS.iter().filter(|solver| [6, 8, 10, 11].contains(&solver.day)) {
+        for seed in 0..3 {
+            let input = generate(solver.day, 15, seed).unwrap();
+            let verdict = cross_check(solver, &input).unwrap();
+            assert!(!matches!(verdict, Verdict::Disagree { .. }), "day {} part {} seed {}: {:?}", solver.day, solver.part, seed, verdict);
+        }
+    }
+}
+
+// Walks of 2, 3 and 4 steps meet after 12 steps, not after their product
+#[test]
+fn cross_check_compares_the_day08_lcm_with_several_walkers() {
+    let solver = solver(8, 2);
+    let input = [
+        "LR",
+        "",
+        "22A = (221, 221)", "221 = (22Z, 22Z)", "22Z = (221, 221)",
+        "33A = (331, 331)", "331 = (332, 332)", "332 = (33Z, 33Z)", "33Z = (331, 331)",
+        "44A = (441, 441)", "441 = (442, 442)", "442 = (443, 443)", "443 = (44Z, 44Z)", "44Z = (441, 441)",
+    ]
+    .join("\n");
+
+    assert!(matches!(cross_check(solver, &input).unwrap(), Verdict::Agree(Answer::Unsigned(12))));
+    for seed in 0..3 {
+        let input = generate(8, 15, seed).unwrap();
+        assert!(matches!(cross_check(solver, &input).unwrap(), Verdict::Agree(_)), "seed {}", seed);
+    }
+}
+
+// Counts the `#` of every line but the last one, which the reference does not forget
+fn hashes_but_the_last_line(input: &str) -> Result<Outcome, RunError> {
+    let lines: Vec<&str> = input.lines().collect();
+    let hashes = lines[..lines.len().saturating_sub(1)].iter().map(|line| line.matches('#').count()).sum::<usize>();
+    Ok(Outcome { answer: hashes.into(), timings: Timings::default(), allocations: None })
+}
+
+fn all_hashes(input: &str) -> Result<Option<Answer>, ParseError> {
+    Ok(Some(input.matches('#').count().into()))
+}
+
+#[test]
+fn cross_check_shrinks_a_disagreement() {
+    let solver = Solver { day: 0, part: 1, run: hashes_but_the_last_line, reference: all_hashes };
+
+    match cross_check(&solver, "#.\n..\n#.\n.#").unwrap() {
+        Verdict::Disagree { smallest, .. } => assert_eq!(smallest.input, ".#"),
+        verdict => panic!("expected a disagreement, got {:?}", verdict),
+    }
+}
+
+#[test]
+fn quiet_panics_are_still_caught() {
+    let caught = quietly(|| panic::catch_unwind(|| panic!("not printed")));
+
+    assert!(caught.is_err());
+    assert!(quietly(|| panic::catch_unwind(|| quietly(|| panic!("not printed either")))).is_err());
+    assert_eq!(quietly(|| 42), 42);
+}
+
+fn no_answer(_input: &str) -> Result<Outcome, RunError> {
+    Err(SolveError::new("no answer").with_day(0).into())
+}
+
+#[test]
+fn solve_errors_are_recorded_as_errors() {
+    let solver = Solver { day: 0, part: 1, run: no_answer, reference: all_hashes };
+    let record = Record::new(&solver, "-".to_string(), (solver.run)(""));
+
+    assert_eq!(record.result, Err("solve error: no answer".to_string()));
+}
+
+#[test]
+fn start_counts_as_the_pipe_it_stands_for() {
+    let solver = solver(10, 2);
+
+    assert_eq!((solver.run)("F-7\nS.|\nL-J").unwrap().answer.to_string(), "1");
+    assert!(matches!(cross_check(solver, "......\n.F--7.\n.S..|.\n.L--J.\n......").unwrap(), Verdict::Agree(_)));
+}
+
+#[test]
+fn trace_events_list_their_fields() {
+    let hand = format_event(Level::Debug, "aoc2023::day07", "hand", &[("cards", &"KTJJT"), ("rank", &3)]);
+    let grid = format_event(Level::Info, "aoc2023::day11", "expanded_grid", &[("grid", &Shown("#.\n.#"))]);
+
+    assert_eq!(hand, "[debug] day07 hand cards=\"KTJJT\" rank=3");
+    assert_eq!(grid, "[info] day11 expanded_grid grid=\n#.\n.#");
+}
+
+#[test]
+fn watch_reruns_only_changed_inputs() {
+    let path = std::env::temp_dir().join(format!("aoc2023-watch-{}.txt", std::process::id()));
+    fs::write(&path, "Time: 7 15\nDistance: 9 40").unwrap();
+    let solvers = vec![solver(6, 1)];
+    let mut watcher = Watcher::new(&solvers, vec![path.clone()]);
+
+    let first = watcher.poll();
+    let unchanged = watcher.poll();
+    fs::write(&path, "Time: 7 15 30\nDistance: 9 40 200").unwrap();
+    let changed = watcher.poll();
+    fs::remove_file(&path).unwrap();
+
+    assert!(first[1].starts_with("  part 1: 32  "));
+    assert!(unchanged.is_empty());
+    assert!(changed[1].starts_with("  part 1: 288 (was 32)  "));
+}
+
+#[test]
+fn allocations_are_counted_with_the_feature_only() {
+    let (buffer, allocations) = measure(|| vec![0_u8; 1 << 20]);
+    assert_eq!(buffer.len(), 1 << 20);
+    assert_eq!(allocations.is_some(), ENABLED);
+
+    // Other tests allocate concurrently, so only lower bounds hold
+    if let Some(allocations) = allocations {
+        assert!(allocations.count >= 1);
+        assert!(allocations.bytes >= 1 << 20);
+    }
+}
+
+#[test]
+fn bytes_are_formatted_in_binary_units() {
+    assert_eq!(format_bytes(0), "0B");
+    assert_eq!(format_bytes(1023), "1023B");
+    assert_eq!(format_bytes(1536), "1.5KiB");
+    assert_eq!(format_bytes(3 << 20), "3.0MiB");
+    assert_eq!(format_bytes(5 << 40), "5.0TiB");
+}
+
+#[test]
+fn counterexamples_are_shrunk() {
+    let large = find_counterexample(&vectors(integers(0..=100), 0..=20), 0, 100, |values| values.iter().all(|value| *value < 50));
+    assert_eq!(large, Some(vec![50]));
+
+    let repeated = find_counterexample(&strings("ab", 0..=10), 0, 100, |string| !string.contains("bb"));
+    assert_eq!(repeated.as_deref(), Some("bb"));
+
+    // Panics count as failures
+    let panicking = find_counterexample(&integers(-10..=10), 0, 100, |value| 10 / value > -100);
+    assert_eq!(panicking, Some(0));
+
+    assert_eq!(find_counterexample(&integers(1..=5), 0, 100, |value| *value > 0), None);
+}
+
+#[test]
+fn parsed_numbers_round_trip() {
+    property::check(vectors(integers(-1000..=1000), 0..=10), |numbers| {
+        let line = numbers.iter().map(i64::to_string).collect::<Vec<_>>().join(" ");
+        parse_numbers::<i64>(&line, &line).unwrap() == *numbers
+    });
+}
+
+fn typed_hand(cards: &str, jokers: bool) -> TypedHand {
+    let hand = InputHand::try_from(format!("{} 1", cards).as_str()).unwrap();
+    TypedHand::from(if jokers { hand.with_jokers() } else { hand })
+}
+
+#[test]
+fn hand_ranking_is_a_total_order() {
+    let hand = || strings("23456789TJQKA", 5..=5);
+    property::check(((hand(), hand()), (hand(), integers(0..=1))), |((a, b), (c, jokers))| {
+        let [a, b, c] = [a, b, c].map(|cards| typed_hand(cards, *jokers == 1));
+        let ab = compare_hands(&a, &b);
+        let bc = compare_hands(&b, &c);
+
+        compare_hands(&b, &a) == ab.reverse()
+            && (ab == Ordering::Equal) == (a.hand.cards == b.hand.cards)
+            && (ab != bc || compare_hands(&a, &c) == ab)
+    });
+}
+
+#[test]
+fn almanac_maps_without_overlaps_are_bijections() {
+    // Blocks laid out one after the other as sources, and as destinations in
+    // the order of their keys
+    property::check(vectors((integers(1..=20), integers(0..=20)), 1..=8), |blocks| {
+        let mut sources = vec![];
+        let mut start = 0;
+        for (length, _) in blocks {
+            sources.push(start);
+            start += *length as usize;
+        }
+        let size = start;
+
+        let mut order: Vec<usize> = (0..blocks.len()).collect();
+        order.sort_by_key(|index| blocks[*index].1);
+        let mut lines = vec!["seed-to-soil map:".to_string()];
+        let mut destination = 0;
+        for index in order {
+            let length = blocks[index].0 as usize;
+            lines.push(format!("{} {} {}", destination, sources[index], length));
+            destination += length;
+        }
+
+        let map = Map::try_from(lines.as_slice()).unwrap();
+        let mut mapped: Vec<usize> = (0..size).map(|input| map.map(input)).collect();
+        mapped.sort();
+        mapped == (0..size).collect::<Vec<_>>() && (size..size + 10).all(|input| map.map(input) == input)
+    });
+}
+
+#[test]
+fn polynomial_histories_are_extrapolated_exactly() {
+    // Coefficients from the constant term up, and how many more values than
+    // the degree needs
+    property::check((vectors(integers(-5..=5), 1..=6), integers(0..=10)), |(coefficients, extra)| {
+        let value = |x: i64| coefficients.iter().rev().fold(0, |value, coefficient| value * x + coefficient);
+        let length = coefficients.len() as i64 + 1 + extra;
+        let line = (0..length).map(|x| value(x).to_string()).collect::<Vec<_>>().join(" ");
+
+        let reading = DataReading::try_from(line.as_str()).unwrap();
+        reading.next_value() == value(length) && reading.previous_value() == value(-1)
+    });
+}
+
+#[test]
+fn offsets_with_factor_2_match_the_expanded_grid() {
+    // Cells read row by row, the last row filled up with empty cells
+    property::check((integers(1..=8), strings(".#", 1..=64)), |(width, cells)| {
+        let width = *width as usize;
+        let mut cells = cells.clone();
+        while !cells.len().is_multiple_of(width) {
+            cells.push('.');
+        }
+        let rows: Vec<&str> = (0..cells.len()).step_by(width).map(|start| &cells[start..start + width]).collect();
+
+        let grid = Day11::parse(&rows.join("\n")).unwrap();
+        sum_of_distances_with_offsets(&grid, 2) == sum_of_distances_in_expanded_grid(&grid)
+    });
+}
+
+// The inputs of the day and a few generated ones
+fn inputs_of_day(day: u8) -> Vec<String> {
+    let directory = solver(day, 1).directory();
+    let mut inputs: Vec<String> = input_paths(&directory).unwrap().iter().map(|path| fs::read_to_string(path).unwrap()).collect();
+    inputs.extend((0..3).map(|seed| generate(day, 12, seed).unwrap()));
+    inputs
+}
+
+fn check_round_trip<S: Solution>(display: impl Fn(&S::Input) -> String)
+where
+    S::Input: PartialEq + fmt::Debug,
+{
+    for input in inputs_of_day(S::DAY) {
+        let parsed = S::parse(&input).unwrap();
+        let displayed = display(&parsed);
+        assert_eq!(S::parse(&displayed).unwrap(), parsed, "day {} displayed as:\n{}", S::DAY, displayed);
+    }
+}
+
+fn lines<T: fmt::Display>(items: &[T]) -> String {
+    items.iter().map(T::to_string).collect::<Vec<_>>().join("\n")
+}
+
+#[test]
+fn displayed_inputs_parse_back_to_the_same_model() {
+    check_round_trip::<Day02>(|games| lines(games));
+    check_round_trip::<Day04>(|cards| lines(cards));
+    check_round_trip::<Day05>(|almanac| almanac.to_string());
+    check_round_trip::<Day06>(|races| races.to_string());
+    check_round_trip::<Day07>(|hands| lines(hands));
+    check_round_trip::<Day08>(|map| map.to_string());
+    check_round_trip::<Day09>(|readings| readings.to_string());
+    check_round_trip::<Day10>(|maze| maze.to_string());
+}
+
+#[test]
+fn generated_cards_are_displayed_as_generated() {
+    for seed in 0..3 {
+        let input = generate(4, 20, seed).unwrap();
+        assert_eq!(lines(&Day04::parse(&input).unwrap()), input, "seed {}", seed);
+    }
+}
+
+// Inputs that keep their order once parsed are written back as they were
+#[test]
+fn examples_are_displayed_as_written() {
+    let example = |day: u8| {
+        let directory = solver(day, 1).directory();
+        fs::read_to_string(directory.join("example_input.txt")).unwrap().trim_end().to_string()
+    };
+
+    assert_eq!(Day06::parse(&example(6)).unwrap().to_string(), example(6));
+    assert_eq!(lines(&Day07::parse(&example(7)).unwrap()), example(7));
+    assert_eq!(Day09::parse(&example(9)).unwrap().to_string(), example(9));
+    assert_eq!(Day10::parse(&example(10)).unwrap().to_string(), example(10));
+}
+
+#[test]
+fn empty_seeds_and_races_are_rejected() {
+    assert_eq!(Day05::parse("seeds:\n\nseed-to-soil map:\n1 2 3").unwrap_err().expected, "at least one seed");
+    assert_eq!(Day06::parse("Time:\nDistance:").unwrap_err().expected, "at least one race");
+
+    assert_eq!(solve_error(5, 2, "seeds: 79 0\n\nseed-to-soil map:\n1 2 3").message, "no seed in the seed ranges");
+    assert_eq!(solve_error(6, 2, "Time: 99999999999 99999999999\nDistance: 1 1").message, "the single race does not fit in a usize");
+}
+
+#[test]
+fn day09_short_histories_end_on_a_constant() {
+    let extrapolate = |line: &str| {
+        let reading = DataReading::try_from(line).unwrap();
+        (reading.previous_value(), reading.next_value())
+    };
+
+    assert_eq!(extrapolate("5"), (5, 5));
+    assert_eq!(extrapolate("1 2 4 8"), (0, 15));
+    property::check(vectors(integers(-1000..=1000), 1..=8), |values| {
+        let line = values.iter().map(i64::to_string).collect::<Vec<_>>().join(" ");
+        extrapolate(&line);
+        true
+    });
+}
+
+#[test]
+fn day08_walks_without_an_end_are_reported() {
+    let solver = solver(8, 1);
+    let example = fs::read_to_string(solver.directory().join("example_input_part2.txt")).unwrap();
+    let no_end = "L\n\nAAA = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)";
+
+    assert_eq!(solve_error(8, 1, &example), SolveError::new("no `AAA` node").with_day(8));
+    assert_eq!(solve_error(8, 1, no_end).message, "`ZZZ` cannot be reached from `AAA`");
+    assert_eq!(solve_error(8, 2, no_end).message, "`AAA` never reaches a `..Z` node");
+}
+
+#[test]
+fn day08_without_start_nodes_takes_no_steps() {
+    let solver = solver(8, 2);
+    let input = "L\n\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)";
+
+    assert_eq!((solver.run)(input).unwrap().answer.to_string(), "0");
+    assert!(matches!(cross_check(solver, input).unwrap(), Verdict::Agree(_)));
+}
+
+// One walk per prime up to 53, each reaching its end node after that many
+// steps: they only all stand on an end node after their product, 3.3 * 10^19
+#[test]
+fn day08_walks_meeting_after_u64_max_steps_are_reported() {
+    let primes = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53];
+    let mut input = "L\n".to_string();
+    for (walk, prime) in primes.into_iter().enumerate() {
+        let letter = (b'B' + walk as u8) as char;
+        let id = |step: usize| match step {
+            0 => format!("{}AA", letter),
+            step if step == prime => format!("{}ZZ", letter),
+            step => format!("{}{:02}", letter, step),
+        };
+        for step in 0..prime {
+            input += &format!("\n{} = ({}, {})", id(step), id(step + 1), id(step + 1));
+        }
+        input += &format!("\n{} = ({}, {})", id(prime), id(prime), id(prime));
+    }
+
+    assert_eq!(solve_error(8, 2, &input).message, "more than u64::MAX steps");
+}
+
+#[test]
+fn baselines_keep_the_total_median() {
+    let path = std::env::temp_dir().join(format!("aoc2023-baseline-{}.txt", std::process::id()));
+    let medians = Medians { parse: Duration::from_nanos(10), solve: Duration::from_nanos(20), total: Some(Duration::from_nanos(40)) };
+    write_baseline(&path, &Baseline::from([((6, 1), medians)])).unwrap();
+    let saved = read_baseline(&path);
+
+    // Lines written before the total was recorded have none
+    fs::write(&path, "6 1 10 20\n").unwrap();
+    let old = read_baseline(&path);
+    fs::write(&path, "6 1 ten 20\n").unwrap();
+    let invalid = read_baseline(&path);
+    // Day 266 is not day 10
+    fs::write(&path, "266 1 10 20 40\n").unwrap();
+    let out_of_range = read_baseline(&path);
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(saved.unwrap()[&(6, 1)], medians);
+    assert_eq!(old.unwrap()[&(6, 1)].total, None);
+    assert_eq!(invalid.unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+    assert_eq!(out_of_range.unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn card_errors_point_at_the_missing_separator() {
+    let error = Day04::parse("Card 1: 41 48 | 83 86\nCard 2: 13 32 20 61").unwrap_err();
+    assert_eq!((error.line, error.expected.as_str()), (Some(2), "`|` somewhere"));
+
+    let error = Day04::parse("Card x: 41 | 48").unwrap_err();
+    assert_eq!((error.column, error.found()), (6, "x".to_string()));
+}
@@ -0,0 +1,93 @@
+
+// Opt-in trace of what the solvers do, one event per line on stderr or in a
+// trace file. `-v` shows the `Info` events, `-vv` the `Debug` ones as well:
+//
+//     event!(Debug, "hand", cards = hand.cards, bid = hand.bid);
+//
+// prints `[debug] day07 hand cards=[K, T, J, J, T] bid=220`. The fields are only
+// evaluated when their level is enabled.
+
+use std::fmt::{self, Write as _};
+use std::fs::File;
+use std::io::{self, LineWriter, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::Mutex;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Level {
+    Info = 1,
+    Debug = 2,
+}
+
+impl Level {
+    fn name(&self) -> &'static str {
+        match self {
+            Level::Info => "info",
+            Level::Debug => "debug",
+        }
+    }
+}
+
+// 0 while tracing is off
+static LEVEL: AtomicU8 = AtomicU8::new(0);
+// Stderr when `None`
+static TRACE_FILE: Mutex<Option<LineWriter<File>>> = Mutex::new(None);
+
+pub fn enable(level: Level, file: Option<&Path>) -> io::Result<()> {
+    if let Some(path) = file {
+        *TRACE_FILE.lock().unwrap() = Some(LineWriter::new(File::create(path)?));
+    }
+    LEVEL.store(level as u8, Ordering::Relaxed);
+    Ok(())
+}
+
+pub fn enabled(level: Level) -> bool {
+    LEVEL.load(Ordering::Relaxed) >= level as u8
+}
+
+// `module` is a module path, the event is labelled with its last segment
+pub fn format_event(level: Level, module: &str, name: &str, fields: &[(&str, &dyn fmt::Debug)]) -> String {
+    let mut line = format!("[{}] {} {}", level.name(), module.rsplit("::").next().unwrap_or(module), name);
+    for (key, value) in fields {
+        let value = format!("{:?}", value);
+        // Multi-line values, like grids, start on their own line
+        let separator = if value.contains('\n') { "\n" } else { "" };
+        let _ = write!(line, " {}={}{}", key, separator, value);
+    }
+    line
+}
+
+pub fn emit(level: Level, module: &str, name: &str, fields: &[(&str, &dyn fmt::Debug)]) {
+    let line = format_event(level, module, name, fields);
+
+    // A trace that cannot be written is not worth failing the solver for
+    let mut trace_file = TRACE_FILE.lock().unwrap();
+    let _ = match trace_file.as_mut() {
+        Some(file) => writeln!(file, "{}", line),
+        None => writeln!(io::stderr(), "{}", line),
+    };
+}
+
+// Traces a value with `Display` rather than `Debug`, e.g. a grid as it prints
+pub struct Shown<T>(pub T);
+
+impl<T: fmt::Display> fmt::Debug for Shown<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+macro_rules! event {
+    ($level:ident, $name:literal $(, $key:ident = $value:expr)* $(,)?) => {
+        if $crate::trace::enabled($crate::trace::Level::$level) {
+            $crate::trace::emit(
+                $crate::trace::Level::$level,
+                module_path!(),
+                $name,
+                &[$((stringify!($key), &$value as &dyn ::std::fmt::Debug)),*],
+            );
+        }
+    };
+}
+pub(crate) use event;
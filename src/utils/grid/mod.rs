@@ -0,0 +1,164 @@
+
+use std::{fmt, ops::{Index, IndexMut}};
+
+use crate::solution::ParseError;
+
+use super::geometry::{Direction, Direction8, Point, Vector};
+use super::split_input_into_lines;
+
+// Positions are `(x, y)`: `x` is the column, `y` the line, both from the top left
+pub type Position = (usize, usize);
+
+// A rectangular grid stored line after line
+#[derive(Clone, Debug, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    // `parse_cell` gets the position of each character and returns `None`
+    // for characters that are not `expected`
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        mut parse_cell: impl FnMut(Position, char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let lines = split_input_into_lines(input);
+        let width = lines.first().map(|line| line.chars().count()).unwrap_or_default();
+
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (y, line) in lines.iter().enumerate() {
+            if line.chars().count() != width {
+                return Err(ParseError::line(line, &format!("a line of {} characters like the first one", width)).on_line(y));
+            }
+
+            for (x, (start, char)) in line.char_indices().enumerate() {
+                let cell = parse_cell((x, y), char)
+                    .ok_or_else(|| ParseError::span(line, start, char.len_utf8(), expected).on_line(y))?;
+                cells.push(cell);
+            }
+        }
+
+        Ok(Grid {
+            width,
+            height: lines.len(),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): Position) -> Option<&T> {
+        if x < self.width && y < self.height {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (x, y): Position) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            self.cells.get_mut(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    // The position `vector` away from `position`, if it is still in the grid
+    pub fn offset(&self, position: Position, vector: Vector) -> Option<Position> {
+        Point::from(position)
+            .checked_add(vector)
+            .filter(|point| point.x < self.width && point.y < self.height)
+            .map(Position::from)
+    }
+
+    // Neighbours that are in the grid, clockwise from north
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL.into_iter().filter_map(move |direction| self.offset(position, direction.vector()))
+    }
+
+    // Same as `neighbours4` with the diagonals
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction8::ALL.into_iter().filter_map(move |direction| self.offset(position, direction.vector()))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |index| (index % width, index / width))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` does not accept 0, an empty grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1)).take(self.height)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    // Inserts `row` before line `y`, `y` can be the height to append it
+    pub fn insert_row(&mut self, y: usize, row: Vec<T>) {
+        assert_eq!(row.len(), self.width, "a row must be as wide as the grid");
+        let index = y * self.width;
+        self.cells.splice(index..index, row);
+        self.height += 1;
+    }
+
+    // Inserts `column` before column `x`, `x` can be the width to append it
+    pub fn insert_column(&mut self, x: usize, column: Vec<T>) {
+        assert_eq!(column.len(), self.height, "a column must be as high as the grid");
+        for (y, cell) in column.into_iter().enumerate().rev() {
+            self.cells.insert(y * self.width + x, cell);
+        }
+        self.width += 1;
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position).unwrap_or_else(|| panic!("{:?} is outside of a {}x{} grid", position, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position).unwrap_or_else(|| panic!("{:?} is outside of a {}x{} grid", position, width, height))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,123 @@
+
+// Number theory helpers. Everything that can overflow is checked and returns
+// `None` instead of wrapping.
+
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+// 1 for no numbers at all, the neutral element
+pub fn lcm_of(numbers: impl IntoIterator<Item = u64>) -> Option<u64> {
+    numbers.into_iter().try_fold(1, lcm)
+}
+
+pub fn gcd_of(numbers: impl IntoIterator<Item = u64>) -> u64 {
+    numbers.into_iter().fold(0, gcd)
+}
+
+// `(g, x, y)` with `a * x + b * y = g = gcd(a, b)`
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+pub fn modulo(a: i128, modulus: i128) -> i128 {
+    a.rem_euclid(modulus)
+}
+
+// The inverse of `a` modulo `modulus`, if they are coprime
+pub fn mod_inverse(a: i128, modulus: i128) -> Option<i128> {
+    let (g, x, _) = extended_gcd(modulo(a, modulus), modulus);
+    (g == 1).then(|| modulo(x, modulus))
+}
+
+// Products stay in `i128` as long as `modulus` fits in 63 bits
+pub fn mod_pow(base: i128, mut exponent: u64, modulus: i128) -> i128 {
+    let mut base = modulo(base, modulus);
+    let mut result = modulo(1, modulus);
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+    result
+}
+
+// Chinese Remainder Theorem over `(remainder, modulus)` pairs, the moduli do
+// not have to be coprime. Returns `(x, m)`: every solution is `x` modulo `m`,
+// with `0 <= x < m`. `None` when the congruences contradict each other or
+// `m` overflows.
+pub fn crt(congruences: impl IntoIterator<Item = (i128, i128)>) -> Option<(i128, i128)> {
+    congruences.into_iter().try_fold((0, 1), |(x, m), (remainder, modulus)| {
+        let (g, p, _) = extended_gcd(m, modulus);
+        let difference = remainder - x;
+        if difference % g != 0 {
+            return None;
+        }
+
+        let step = modulus / g;
+        let lcm = m.checked_mul(step)?;
+        // `p` is the inverse of `m / g` modulo `step`
+        let k = modulo(modulo(difference / g, step).checked_mul(modulo(p, step))?, step);
+        Some((modulo(x.checked_add(m.checked_mul(k)?)?, lcm), lcm))
+    })
+}
+
+// The largest `r` with `r * r <= n`
+pub fn isqrt(n: u64) -> u64 {
+    let n = n as u128;
+    // The float estimate can be off by a little for large numbers
+    let mut root = (n as f64).sqrt() as u128;
+    while root * root > n {
+        root -= 1;
+    }
+    while (root + 1) * (root + 1) <= n {
+        root += 1;
+    }
+    root as u64
+}
+
+// `n` choose `k`
+pub fn binomial(n: u64, k: u64) -> Option<u64> {
+    if k > n {
+        return Some(0);
+    }
+
+    let k = k.min(n - k);
+    let mut result: u64 = 1;
+    for i in 0..k {
+        // Dividing by the gcd first keeps the intermediate product small,
+        // `result * (n - i)` is always a multiple of `i + 1`
+        let (numerator, denominator) = (n - i, i + 1);
+        let g = gcd(result, denominator);
+        let (result_part, denominator) = (result / g, denominator / g);
+        result = result_part.checked_mul(numerator / denominator)?;
+    }
+    Some(result)
+}
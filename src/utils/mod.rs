@@ -1,6 +1,85 @@
 
+pub mod geometry;
+pub mod grid;
+pub mod math;
+pub mod panics;
+pub mod parsing;
+pub mod property;
+pub mod random;
+
+use std::time::Duration;
+
 // Input
 
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+// Lines without their `\n` or `\r\n` ending, and without the blank lines
+// editors tend to leave at the end of the file
 pub fn split_input_into_lines(input: &str) -> Vec<String> {
-    input.split('\n').map(String::from).collect()
+    let mut lines: Vec<String> = input
+        .lines()
+        .map(|line| line.strip_suffix('\r').unwrap_or(line).to_string())
+        .collect();
+    while lines.last().is_some_and(|line| is_blank(line)) {
+        lines.pop();
+    }
+    lines
+}
+
+// A block of lines separated from the next one by one or more blank lines
+#[derive(Debug, PartialEq)]
+pub struct Section {
+    // 0-based index of the first line in the input, to locate errors with `on_line`
+    pub first_line_index: usize,
+    pub lines: Vec<String>,
+}
+
+pub fn split_input_into_sections(input: &str) -> Vec<Section> {
+    let mut sections = vec![];
+    let mut current: Option<Section> = None;
+
+    for (index, line) in split_input_into_lines(input).into_iter().enumerate() {
+        if is_blank(&line) {
+            sections.extend(current.take());
+        } else {
+            current
+                .get_or_insert_with(|| Section { first_line_index: index, lines: vec![] })
+                .lines
+                .push(line);
+        }
+    }
+    sections.extend(current);
+    sections
+}
+
+// Time
+
+pub fn format_duration(duration: Duration) -> String {
+    let micros = duration.as_secs_f64() * 1_000_000.0;
+    if micros < 1_000.0 {
+        format!("{:.1}µs", micros)
+    } else if micros < 1_000_000.0 {
+        format!("{:.3}ms", micros / 1_000.0)
+    } else {
+        format!("{:.3}s", micros / 1_000_000.0)
+    }
+}
+
+// Memory
+
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{}B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1}{}", value, UNITS[unit])
 }
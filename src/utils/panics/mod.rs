@@ -0,0 +1,45 @@
+
+// Panics that are caught on purpose, e.g. while shrinking a failing input,
+// are not worth printing. The panic hook is shared by the whole process and
+// the tests run on parallel threads, so it is never swapped: a hook installed
+// once stays silent on the threads running `quietly` only.
+
+use std::cell::Cell;
+use std::panic;
+use std::sync::OnceLock;
+
+thread_local! {
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+static HOOK: OnceLock<()> = OnceLock::new();
+
+fn install_hook() {
+    HOOK.get_or_init(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !QUIET.get() {
+                default_hook(info);
+            }
+        }));
+    });
+}
+
+// Restores the previous state, even when `f` panics
+struct QuietGuard {
+    was_quiet: bool,
+}
+
+impl Drop for QuietGuard {
+    fn drop(&mut self) {
+        QUIET.set(self.was_quiet);
+    }
+}
+
+// Runs `f` without printing the panics raised on this thread, which `f` is
+// expected to catch. Panics on the threads it starts are still printed.
+pub fn quietly<T>(f: impl FnOnce() -> T) -> T {
+    install_hook();
+    let _guard = QuietGuard { was_quiet: QUIET.replace(true) };
+    f()
+}
@@ -0,0 +1,41 @@
+
+// Line parsing helpers. Regexes are compiled once per call site with
+// `cached_regex!`, the small combinators below cover the common shapes of
+// puzzle lines without a regex at all. Every error points into `line`.
+
+use std::str::FromStr;
+
+use crate::solution::ParseError;
+
+// A `&'static Regex` compiled the first time this line runs
+macro_rules! cached_regex {
+    ($pattern:literal) => {{
+        static REGEX: std::sync::OnceLock<::regex::Regex> = std::sync::OnceLock::new();
+        REGEX.get_or_init(|| ::regex::Regex::new($pattern).unwrap())
+    }};
+}
+pub(crate) use cached_regex;
+
+// `number` must be a slice of `line`, so that errors point at it
+pub fn parse_number<T: FromStr>(line: &str, number: &str) -> Result<T, ParseError> {
+    number.parse().map_err(|_| ParseError::part(line, number, "a number"))
+}
+
+// Whitespace separated numbers, `numbers` must be a slice of `line`
+pub fn parse_numbers<T: FromStr>(line: &str, numbers: &str) -> Result<Vec<T>, ParseError> {
+    numbers.split_ascii_whitespace().map(|number| parse_number(line, number)).collect()
+}
+
+// What follows `literal` at the start of `rest`, a slice of `line`
+pub fn strip_literal<'a>(line: &str, rest: &'a str, literal: &str) -> Result<&'a str, ParseError> {
+    rest.strip_prefix(literal).ok_or_else(|| {
+        let found = rest.char_indices().nth(literal.chars().count()).map_or(rest.len(), |(end, _)| end);
+        ParseError::part(line, &rest[..found], &format!("`{}`", literal))
+    })
+}
+
+// The parts of `rest`, a slice of `line`, before and after the first `separator`
+pub fn split_once<'a>(line: &str, rest: &'a str, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+    rest.split_once(separator)
+        .ok_or_else(|| ParseError::part(line, rest, &format!("`{}` somewhere", separator)))
+}
@@ -0,0 +1,202 @@
+
+// Small property-based testing harness. A strategy generates random values
+// and lists simpler versions of a value, so that a failing case is shrunk to
+// a small one before it is reported:
+//
+//     check(vectors(integers(0..=100), 0..=10), |values| values.iter().all(|value| *value <= 100));
+//
+// The cases are the same from one run to the next, `PROPERTY_SEED` and
+// `PROPERTY_CASES` change them.
+
+use std::{env, fmt, panic};
+use std::ops::RangeInclusive;
+
+use crate::utils::panics::quietly;
+use crate::utils::random::Rng;
+
+pub const DEFAULT_CASES: usize = 200;
+// Successful shrinking steps before settling for the current value
+const MAX_SHRINK_STEPS: usize = 1_000;
+
+pub trait Strategy {
+    type Value: Clone + fmt::Debug;
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value;
+    // Simpler versions of `value`, the most promising first
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value>;
+}
+
+// Integers
+
+pub struct Integers {
+    range: RangeInclusive<i64>,
+}
+
+pub fn integers(range: RangeInclusive<i64>) -> Integers {
+    Integers { range }
+}
+
+impl Strategy for Integers {
+    type Value = i64;
+
+    fn generate(&self, rng: &mut Rng) -> i64 {
+        rng.signed_range(self.range.clone())
+    }
+
+    // Towards 0, or the bound of the range closest to it, halving the distance
+    fn shrink(&self, value: &i64) -> Vec<i64> {
+        let target = 0.clamp(*self.range.start(), *self.range.end());
+        let mut candidates = vec![];
+        let mut distance = *value - target;
+        while distance != 0 {
+            candidates.push(*value - distance);
+            distance /= 2;
+        }
+        candidates
+    }
+}
+
+// Vectors
+
+pub struct Vectors<S> {
+    element: S,
+    lengths: RangeInclusive<usize>,
+}
+
+pub fn vectors<S: Strategy>(element: S, lengths: RangeInclusive<usize>) -> Vectors<S> {
+    Vectors { element, lengths }
+}
+
+impl<S: Strategy> Strategy for Vectors<S> {
+    type Value = Vec<S::Value>;
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value {
+        let length = rng.range(self.lengths.clone());
+        (0..length).map(|_| self.element.generate(rng)).collect()
+    }
+
+    // Removes ever smaller blocks of elements, then simplifies the elements one by one
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value> {
+        let mut candidates = vec![];
+
+        let removable = value.len().saturating_sub(*self.lengths.start());
+        let mut block = removable;
+        while block > 0 {
+            let mut start = 0;
+            while start + block <= value.len() {
+                candidates.push([&value[..start], &value[start + block..]].concat());
+                start += block;
+            }
+            block /= 2;
+        }
+
+        for (index, element) in value.iter().enumerate() {
+            for simpler in self.element.shrink(element) {
+                let mut candidate = value.clone();
+                candidate[index] = simpler;
+                candidates.push(candidate);
+            }
+        }
+
+        candidates
+    }
+}
+
+// Strings
+
+// Shrinks towards the first character of the alphabet
+pub struct Chars {
+    alphabet: Vec<char>,
+}
+
+impl Strategy for Chars {
+    type Value = char;
+
+    fn generate(&self, rng: &mut Rng) -> char {
+        *rng.pick(&self.alphabet)
+    }
+
+    fn shrink(&self, value: &char) -> Vec<char> {
+        self.alphabet.iter().take(1).filter(|char| *char != value).copied().collect()
+    }
+}
+
+pub struct Strings {
+    chars: Vectors<Chars>,
+}
+
+pub fn strings(alphabet: &str, lengths: RangeInclusive<usize>) -> Strings {
+    Strings { chars: vectors(Chars { alphabet: alphabet.chars().collect() }, lengths) }
+}
+
+impl Strategy for Strings {
+    type Value = String;
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        self.chars.generate(rng).into_iter().collect()
+    }
+
+    fn shrink(&self, value: &String) -> Vec<String> {
+        let chars: Vec<char> = value.chars().collect();
+        self.chars.shrink(&chars).into_iter().map(|chars| chars.into_iter().collect()).collect()
+    }
+}
+
+// Pairs, nested for more values
+
+impl<A: Strategy, B: Strategy> Strategy for (A, B) {
+    type Value = (A::Value, B::Value);
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value {
+        (self.0.generate(rng), self.1.generate(rng))
+    }
+
+    fn shrink(&self, (a, b): &Self::Value) -> Vec<Self::Value> {
+        self.0
+            .shrink(a)
+            .into_iter()
+            .map(|a| (a, b.clone()))
+            .chain(self.1.shrink(b).into_iter().map(|b| (a.clone(), b)))
+            .collect()
+    }
+}
+
+// Checking
+
+// A panicking property fails like one returning false
+fn fails<T>(property: &impl Fn(&T) -> bool, value: &T) -> bool {
+    panic::catch_unwind(panic::AssertUnwindSafe(|| !property(value))).unwrap_or(true)
+}
+
+fn shrink<S: Strategy>(strategy: &S, property: &impl Fn(&S::Value) -> bool, mut value: S::Value) -> S::Value {
+    for _ in 0..MAX_SHRINK_STEPS {
+        match strategy.shrink(&value).into_iter().find(|candidate| fails(property, candidate)) {
+            Some(simpler) => value = simpler,
+            None => break,
+        }
+    }
+    value
+}
+
+// The shrunk first failing case among `cases` generated from `seed`
+pub fn find_counterexample<S: Strategy>(strategy: &S, seed: u64, cases: usize, property: impl Fn(&S::Value) -> bool) -> Option<S::Value> {
+    let mut rng = Rng::new(seed);
+    let failing = (0..cases).map(|_| strategy.generate(&mut rng)).find(|value| fails(&property, value))?;
+
+    // Many candidates may make the property panic, the first panic was shown already
+    Some(quietly(|| shrink(strategy, &property, failing)))
+}
+
+fn env_or<T: std::str::FromStr>(name: &str, default: T) -> T {
+    env::var(name).ok().and_then(|value| value.parse().ok()).unwrap_or(default)
+}
+
+// Panics with the smallest failing value found, for use in tests
+pub fn check<S: Strategy>(strategy: S, property: impl Fn(&S::Value) -> bool) {
+    let seed = env_or("PROPERTY_SEED", 0);
+    let cases = env_or("PROPERTY_CASES", DEFAULT_CASES);
+
+    if let Some(value) = find_counterexample(&strategy, seed, cases, property) {
+        panic!("Property failed (PROPERTY_SEED={}), smallest failing value: {:?}", seed, value);
+    }
+}
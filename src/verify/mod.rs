@@ -0,0 +1,88 @@
+
+use std::{fs, path::Path};
+
+use super::answers::{expected_answer, input_paths, NOT_APPLICABLE};
+use super::registry::Solver;
+
+enum Status {
+    Pass(String),
+    Fail { expected: String, actual: String },
+    Missing,
+    Error(String),
+}
+
+fn difference(expected: &str, actual: &str) -> String {
+    match (expected.parse::<i128>(), actual.parse::<i128>()) {
+        (Ok(expected), Ok(actual)) => format!(" ({:+})", actual - expected),
+        _ => String::new(),
+    }
+}
+
+fn check(solver: &Solver, input_path: &Path) -> Option<Status> {
+    let expected = match expected_answer(input_path, solver.part) {
+        Ok(Some(expected)) if expected == NOT_APPLICABLE => return None,
+        Ok(Some(expected)) => expected,
+        Ok(None) => return Some(Status::Missing),
+        Err(error) => return Some(Status::Error(error.to_string())),
+    };
+
+    let input = match fs::read_to_string(input_path) {
+        Ok(input) => input,
+        Err(error) => return Some(Status::Error(error.to_string())),
+    };
+
+    Some(match (solver.run)(&input) {
+        Ok(outcome) if outcome.answer.to_string() == expected => Status::Pass(expected),
+        Ok(outcome) => Status::Fail { expected, actual: outcome.answer.to_string() },
+        Err(error) => Status::Error(error.summary()),
+    })
+}
+
+// Returns whether every recorded answer still matches
+pub fn verify(solvers: &[&Solver]) -> bool {
+    let (mut passed, mut failed, mut missing, mut errors) = (0, 0, 0, 0);
+
+    for solver in solvers {
+        let input_paths = match input_paths(&solver.directory()) {
+            Ok(input_paths) => input_paths,
+            Err(error) => {
+                println!("ERROR    day {:02} part {}  {}", solver.day, solver.part, error);
+                errors += 1;
+                continue;
+            }
+        };
+
+        for input_path in input_paths {
+            let input_name = input_path.file_name().unwrap_or_default().to_string_lossy().to_string();
+            let status = match check(solver, &input_path) {
+                Some(status) => status,
+                None => continue,
+            };
+
+            let label = format!("day {:02} part {}  {:<24}", solver.day, solver.part, input_name);
+            match status {
+                Status::Pass(answer) => {
+                    println!("PASS     {}  {}", label, answer);
+                    passed += 1;
+                }
+                Status::Fail { expected, actual } => {
+                    println!("FAIL     {}  expected {}, got {}{}", label, expected, actual, difference(&expected, &actual));
+                    failed += 1;
+                }
+                Status::Missing => {
+                    println!("MISSING  {}  no recorded answer", label);
+                    missing += 1;
+                }
+                Status::Error(error) => {
+                    println!("ERROR    {}  {}", label, error);
+                    errors += 1;
+                }
+            }
+        }
+    }
+
+    println!();
+    println!("{} passed, {} failed, {} missing, {} errors", passed, failed, missing, errors);
+
+    failed == 0 && errors == 0
+}
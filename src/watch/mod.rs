@@ -0,0 +1,140 @@
+
+// `watch` polls input files and re-runs the selected parts whenever one of
+// them changes, showing how the answers and timings moved since the last run.
+// Only the inputs are watched: changing the code still needs a rebuild.
+
+use std::{fs, panic, thread};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use super::registry::Solver;
+use super::solution::{Answer, Timings};
+use super::utils::format_duration;
+
+// What changes when a file is written to
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Stamp {
+    modified: SystemTime,
+    length: u64,
+}
+
+fn stamp(path: &Path) -> Option<Stamp> {
+    let metadata = fs::metadata(path).ok()?;
+    Some(Stamp {
+        modified: metadata.modified().ok()?,
+        length: metadata.len(),
+    })
+}
+
+type PartResult = Result<(Answer, Timings), String>;
+
+struct WatchedFile {
+    path: PathBuf,
+    stamp: Option<Stamp>,
+    // One per solver, `None` before the first run
+    previous: Vec<Option<PartResult>>,
+}
+
+pub struct Watcher<'a> {
+    solvers: &'a [&'a Solver],
+    files: Vec<WatchedFile>,
+}
+
+fn run(solver: &Solver, input: &str) -> PartResult {
+    // A half written input may well make a solver panic: that must not end the watch
+    match panic::catch_unwind(|| (solver.run)(input)) {
+        Ok(Ok(outcome)) => Ok((outcome.answer, outcome.timings)),
+        Ok(Err(error)) => Err(error.summary()),
+        Err(_) => Err("panicked".to_string()),
+    }
+}
+
+// One line per part, e.g. `part 1: 150 (was 142)  1.204ms (was 1.310ms)`
+pub fn describe(part: u8, previous: Option<&PartResult>, current: &PartResult) -> String {
+    let (answer, timings) = match current {
+        Ok(result) => result,
+        Err(error) => return format!("part {}: {}", part, error),
+    };
+
+    let time = format_duration(timings.total());
+    match previous {
+        Some(Ok((previous_answer, previous_timings))) => {
+            let answer_change = if previous_answer == answer {
+                "unchanged".to_string()
+            } else {
+                format!("was {}", previous_answer)
+            };
+            format!("part {}: {} ({})  {} (was {})", part, answer, answer_change, time, format_duration(previous_timings.total()))
+        }
+        _ => format!("part {}: {}  {}", part, answer, time),
+    }
+}
+
+impl<'a> Watcher<'a> {
+    pub fn new(solvers: &'a [&'a Solver], paths: Vec<PathBuf>) -> Self {
+        let files = paths
+            .into_iter()
+            .map(|path| WatchedFile { path, stamp: None, previous: vec![None; solvers.len()] })
+            .collect();
+
+        Self { solvers, files }
+    }
+
+    // Re-runs the solvers on every file that changed since the last poll, and
+    // returns what to print. The first poll runs them on every file.
+    pub fn poll(&mut self) -> Vec<String> {
+        let mut lines = vec![];
+
+        for file in self.files.iter_mut() {
+            let stamp = stamp(&file.path);
+            if stamp.is_none() || stamp == file.stamp {
+                continue;
+            }
+            file.stamp = stamp;
+
+            let input = match fs::read_to_string(&file.path) {
+                Ok(input) => input,
+                Err(error) => {
+                    lines.push(format!("{}: {}", file.path.display(), error));
+                    continue;
+                }
+            };
+
+            lines.push(file.path.display().to_string());
+            for (solver, previous) in self.solvers.iter().zip(file.previous.iter_mut()) {
+                let current = run(solver, &input);
+                lines.push(format!("  {}", describe(solver.part, previous.as_ref(), &current)));
+                *previous = Some(current);
+            }
+        }
+
+        lines
+    }
+
+    pub fn missing_files(&self) -> Vec<&Path> {
+        self.files
+            .iter()
+            .filter(|file| file.stamp.is_none())
+            .map(|file| file.path.as_path())
+            .collect()
+    }
+}
+
+// Runs until interrupted with Ctrl-C
+pub fn watch(solvers: &[&Solver], paths: Vec<PathBuf>, interval: Duration) -> ! {
+    let mut watcher = Watcher::new(solvers, paths);
+
+    watcher.poll().iter().for_each(|line| println!("{}", line));
+    for path in watcher.missing_files() {
+        println!("Waiting for {}", path.display());
+    }
+
+    loop {
+        thread::sleep(interval);
+        let lines = watcher.poll();
+        if !lines.is_empty() {
+            println!();
+            lines.iter().for_each(|line| println!("{}", line));
+        }
+    }
+}
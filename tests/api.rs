@@ -0,0 +1,40 @@
+// The day models are usable from outside the crate, not only through the runner
+
+use aoc2023::day05::Day05;
+use aoc2023::day07::{HandType, InputHand, TypedHand};
+use aoc2023::day10::{Day10, Mouse};
+use aoc2023::registry::SOLVERS;
+use aoc2023::solution::Solution;
+
+#[test]
+fn almanac_maps_a_seed_through_every_map() {
+    let almanac = Day05::parse("seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50 48\n\nsoil-to-fertilizer map:\n0 15 37").unwrap();
+
+    assert_eq!(almanac.seeds, vec![79, 14]);
+    assert_eq!(almanac.map(79), 81);
+}
+
+#[test]
+fn typed_hand_counts_jokers_as_wildcards() {
+    let hand = InputHand::try_from("KTJJT 220").unwrap();
+
+    assert_eq!(TypedHand::from(hand.clone()).hand_type, HandType::TwoPair);
+    assert_eq!(TypedHand::from(hand.with_jokers()).hand_type, HandType::FourOfAKind);
+}
+
+#[test]
+fn mouse_walks_around_the_loop() {
+    let maze = Day10::parse(".....\n.S-7.\n.|.|.\n.L-J.\n.....").unwrap();
+    let mut mouse = Mouse::new(&maze.grid, maze.start);
+    mouse.loop_around().unwrap();
+
+    assert_eq!(mouse.farthest_distance(), 4);
+    assert_eq!(mouse.count_inner_cells(), 1);
+}
+
+#[test]
+fn registry_runs_a_solver_on_any_input() {
+    let solver = SOLVERS.iter().find(|solver| solver.day == 9 && solver.part == 1).unwrap();
+
+    assert_eq!((solver.run)("0 3 6 9 12 15").unwrap().answer.to_string(), "18");
+}